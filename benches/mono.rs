@@ -0,0 +1,235 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use quinine::MonoArc;
+use quinine::MonoArcPadded;
+use quinine::MonoBox;
+use std::sync::Arc;
+
+type BigType = [u64; 128];
+
+const TABLE_SIZE: usize = 1 << 16;
+
+fn bench_drop_empty(c: &mut Criterion) {
+    let mut group = c.benchmark_group("drop_empty");
+
+    group.bench_function("MonoArc", |b| {
+        b.iter_batched(
+            || {
+                (0..TABLE_SIZE)
+                    .map(|_| MonoArc::<BigType>::empty())
+                    .collect::<Vec<_>>()
+            },
+            |table| std::mem::drop(black_box(table)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("MonoBox", |b| {
+        b.iter_batched(
+            || {
+                (0..TABLE_SIZE)
+                    .map(|_| MonoBox::<BigType>::empty())
+                    .collect::<Vec<_>>()
+            },
+            |table| std::mem::drop(black_box(table)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn bench_drop_full(c: &mut Criterion) {
+    let mut group = c.benchmark_group("drop_full");
+
+    let table = || {
+        (0..TABLE_SIZE as u64)
+            .map(MonoArc::from)
+            .collect::<Vec<_>>()
+    };
+
+    group.bench_function("drop", |b| {
+        b.iter_batched(
+            table,
+            |table| std::mem::drop(black_box(table)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.bench_function("drop_all", |b| {
+        b.iter_batched(
+            table,
+            |table| quinine::drop_all(black_box(table)),
+            BatchSize::LargeInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn bench_store_full(c: &mut Criterion) {
+    let mut group = c.benchmark_group("store_full");
+    let mono: MonoBox<u64> = 1.into();
+
+    group.bench_function("store", |b| {
+        b.iter_batched(
+            || Box::new(2u64),
+            |value| black_box(mono.store(value)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("store_hinted", |b| {
+        b.iter_batched(
+            || Box::new(2u64),
+            |value| black_box(mono.store_hinted(value)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn bench_from_box(c: &mut Criterion) {
+    let mut group = c.benchmark_group("from_box");
+
+    group.bench_function("from_box_moving", |b| {
+        b.iter_batched(
+            || MonoBox::<BigType>::from([1u64; 128]),
+            |boxed| black_box(MonoArc::from_box_moving(boxed)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("From<Box>", |b| {
+        b.iter_batched(
+            || Box::new([1u64; 128]),
+            |boxed| black_box(MonoArc::<BigType>::from(boxed)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    // Baseline: allocate the `MonoArc` directly.
+    group.bench_function("new", |b| {
+        b.iter_batched(
+            || [1u64; 128],
+            |value| black_box(MonoArc::<BigType>::from(value)),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone");
+    let mono: MonoArc<u64> = 1.into();
+
+    group.bench_function("clone", |b| b.iter(|| black_box(mono.clone())));
+
+    // Only differs from `clone` on weakly-ordered architectures (e.g.,
+    // ARM), where `Acquire` loads need extra work.
+    group.bench_function("clone_relaxed", |b| {
+        b.iter(|| black_box(unsafe { mono.clone_relaxed() }))
+    });
+
+    group.finish();
+}
+
+type HugeType = [u64; 1 << 14];
+
+fn bench_store_big(c: &mut Criterion) {
+    let mut group = c.benchmark_group("store_big");
+
+    group.bench_function("Arc::new", |b| {
+        b.iter(|| {
+            let mono = MonoArc::<HugeType>::empty();
+            black_box(mono.store(Arc::new([1u64; 1 << 14])).is_ok());
+            mono
+        })
+    });
+
+    // Fill the `Arc`'s allocation in place, without a stack copy.
+    group.bench_function("Arc::new_uninit", |b| {
+        b.iter(|| {
+            let mono = MonoArc::<HugeType>::empty();
+            let mut uninit = Arc::<HugeType>::new_uninit();
+            let slot = Arc::get_mut(&mut uninit).unwrap();
+            let base = slot.as_mut_ptr() as *mut u64;
+            for i in 0..(1 << 14) {
+                unsafe { base.add(i).write(1) };
+            }
+            black_box(mono.store(unsafe { uninit.assume_init() }).is_ok());
+            mono
+        })
+    });
+
+    group.finish();
+}
+
+const NUM_CONTENDERS: usize = 4;
+const CONTENDED_STORES: u64 = 1000;
+
+/// Each thread hammers its own (populated) container with failing
+/// stores: any slowdown comes from false sharing with neighbours.
+fn contended_stores<C: Sync>(
+    iters: u64,
+    table: &[C],
+    store: impl Fn(&C, &std::sync::Arc<u64>) -> bool + Sync,
+) -> std::time::Duration {
+    let start = std::time::Instant::now();
+
+    std::thread::scope(|s| {
+        for cell in table.iter() {
+            let store = &store;
+            s.spawn(move || {
+                // Each thread stores its own `Arc`, to avoid contention
+                // on a shared refcount.
+                let value = std::sync::Arc::new(0u64);
+                for _ in 0..iters * CONTENDED_STORES {
+                    black_box(store(cell, &value));
+                }
+            });
+        }
+    });
+
+    start.elapsed()
+}
+
+fn bench_padded(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contended_store");
+
+    let plain: Vec<MonoArc<u64>> = (0..NUM_CONTENDERS as u64).map(MonoArc::from).collect();
+    let padded: Vec<MonoArcPadded<u64>> = (0..NUM_CONTENDERS as u64)
+        .map(|i| MonoArc::from(i).into())
+        .collect();
+
+    group.bench_function("MonoArc", |b| {
+        b.iter_custom(|iters| {
+            contended_stores(iters, &plain, |cell, value| {
+                cell.store(value.clone()).is_ok()
+            })
+        })
+    });
+
+    group.bench_function("MonoArcPadded", |b| {
+        b.iter_custom(|iters| {
+            contended_stores(iters, &padded, |cell, value| {
+                cell.store(value.clone()).is_ok()
+            })
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_drop_empty,
+    bench_drop_full,
+    bench_store_full,
+    bench_from_box,
+    bench_store_big,
+    bench_clone,
+    bench_padded
+);
+criterion_main!(benches);
@@ -0,0 +1,168 @@
+//! A lock-free append-only linked list, built out of [`MonoBox`]es.
+//!
+//! Each node's `next` pointer is a [`MonoBox`]: appending a node
+//! means walking to the first empty `next` and storing the new node
+//! there.  When another thread wins the race for that cell, our
+//! store fails, we get our node back, and keep walking from the
+//! winner.  Once stored, nodes never move or disappear, so readers
+//! can traverse the committed prefix without any synchronisation
+//! beyond [`MonoBox::as_ref`].
+use quinine::MonoBox;
+
+struct Node<T> {
+    value: T,
+    next: MonoBox<Node<T>>,
+}
+
+/// An append-only list that may be appended to and read from
+/// multiple threads concurrently.
+pub struct AppendList<T> {
+    head: MonoBox<Node<T>>,
+}
+
+impl<T> AppendList<T> {
+    /// Returns a fresh empty list.
+    pub fn new() -> Self {
+        AppendList {
+            head: MonoBox::empty(),
+        }
+    }
+
+    /// Appends `value` at the end of the list.
+    ///
+    /// Appends are linear-time: a real implementation would also
+    /// cache a (racy) hint for the tail node.
+    pub fn append(&self, value: T) {
+        let mut node = Box::new(Node {
+            value,
+            next: MonoBox::empty(),
+        });
+        let mut cell = &self.head;
+
+        loop {
+            match cell.store(node) {
+                Ok(()) => return,
+                Err(rejected) => {
+                    node = rejected;
+                    // A failed store means `cell` is populated.
+                    cell = &cell.as_ref().expect("cell must be populated").next;
+                }
+            }
+        }
+    }
+
+    /// Iterates over the values appended so far.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.head.as_ref(), |node| node.next.as_ref()).map(|node| &node.value)
+    }
+}
+
+impl<T> Default for AppendList<T> {
+    fn default() -> Self {
+        AppendList::new()
+    }
+}
+
+impl<T> Drop for AppendList<T> {
+    fn drop(&mut self) {
+        // Unlink nodes one at a time, to avoid recursing once per
+        // node in `MonoBox`'s destructor.
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+fn main() {
+    let list = AppendList::new();
+
+    std::thread::scope(|s| {
+        for thread in 0..4 {
+            let list = &list;
+            s.spawn(move || {
+                for i in 0..4 {
+                    list.append(thread * 10 + i);
+                }
+            });
+        }
+    });
+
+    let values: Vec<_> = list.iter().collect();
+    println!("{:?}", values);
+}
+
+#[test]
+fn test_sequential() {
+    let list = AppendList::default();
+    assert_eq!(list.iter().count(), 0);
+
+    for i in 0..10 {
+        list.append(i);
+    }
+
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        (0..10).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_concurrent() {
+    const THREADS: usize = 4;
+    const PER_THREAD: usize = 200;
+
+    let list = AppendList::new();
+
+    std::thread::scope(|s| {
+        for thread in 0..THREADS {
+            let list = &list;
+            s.spawn(move || {
+                for i in 0..PER_THREAD {
+                    list.append((thread, i));
+                }
+            });
+        }
+
+        // The reader only sees a prefix of the final list, but each
+        // appender's values always show up in order.
+        let list = &list;
+        s.spawn(move || {
+            for _ in 0..10 {
+                let mut last = [None; THREADS];
+                for &(thread, i) in list.iter() {
+                    assert!(last[thread].is_none_or(|prev| prev < i));
+                    last[thread] = Some(i);
+                }
+            }
+        });
+    });
+
+    let mut values: Vec<_> = list.iter().copied().collect();
+    assert_eq!(values.len(), THREADS * PER_THREAD);
+    values.sort();
+    values.dedup();
+    assert_eq!(values.len(), THREADS * PER_THREAD);
+}
+
+#[test]
+fn test_long_drop() {
+    let list = AppendList::new();
+
+    // Build a long list without going through the quadratic `append`.
+    let mut node = Box::new(Node {
+        value: 0,
+        next: MonoBox::empty(),
+    });
+    for i in 1..100_000 {
+        node = Box::new(Node {
+            value: i,
+            next: node.into(),
+        });
+    }
+    assert!(list.head.store(node).is_ok());
+    assert_eq!(list.iter().count(), 100_000);
+
+    // Must not overflow the stack.
+    std::mem::drop(list);
+}
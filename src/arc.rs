@@ -1,9 +1,12 @@
 extern crate alloc;
 
 use alloc::sync::Arc;
-use core::sync::atomic::AtomicPtr;
-use core::sync::atomic::Ordering;
+use alloc::sync::Weak;
 
+use crate::ordering as Ordering;
+use crate::sync::AtomicPtr;
+use crate::ContainerDebug;
+use crate::Guard;
 use crate::MonoBox;
 
 /// A [`MonoArc<T>`] is an atomic, lock-free, write-once
@@ -17,8 +20,246 @@ use crate::MonoBox;
 /// [`Option<Arc<T>>`].  This non-monotonic operation is safe because
 /// the mutable references guarantees no other thread can observe the
 /// transition.
+///
+/// An empty [`MonoArc`] may also be [`MonoArc::close`]d, which
+/// freezes it as [`None`]: stores to a closed [`MonoArc`] always fail,
+/// and [`MonoArc::is_closed`] distinguishes closed [`MonoArc`]s from
+/// ones that are merely empty (and could still be populated).
+///
+/// Unlike [`MonoBox`], a [`MonoArc`] makes no layout guarantees: the
+/// `debug-tracking` and `debug-monotonic` features add bookkeeping
+/// fields, and Cargo features are unified across the whole build, so
+/// unsafe code must not cast a `&MonoArc<T>` to a `&AtomicPtr<T>`.
 pub struct MonoArc<T> {
+    // Null when empty, `CLOSED` when closed, and the result of
+    // `Arc::into_raw` when populated.
     ptr_or_null: AtomicPtr<T>,
+    // The thread that won the first successful `store`.
+    #[cfg(feature = "debug-tracking")]
+    storing_thread: std::sync::OnceLock<std::thread::ThreadId>,
+    // Whether `swap` and `take` must not discard values.
+    #[cfg(feature = "debug-monotonic")]
+    strict: bool,
+}
+
+/// Sentinel address for closed [`MonoArc`]s.  [`Arc::into_raw`]
+/// returns a pointer past the reference counts at the head of the
+/// `Arc`'s allocation, so it can never collide with this address.
+const CLOSED: usize = 1;
+
+/// Returns `ptr` when it points to a stored value, and null when
+/// `ptr` is null or the [`CLOSED`] sentinel.
+#[cfg_attr(not(tarpaulin), inline(always))]
+fn value_ptr<T>(ptr: *mut T) -> *const T {
+    if ptr as usize == CLOSED {
+        core::ptr::null()
+    } else {
+        ptr
+    }
+}
+
+/// Records a failed store, when the `metrics` feature is enabled.  A
+/// no-op otherwise.
+#[cfg_attr(not(tarpaulin), inline(always))]
+fn note_contended() {
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_store(false);
+}
+
+/// Records a read of `ptr`, when the `metrics` feature is enabled.  A
+/// no-op otherwise.
+#[cfg_attr(not(tarpaulin), inline(always))]
+fn note_read<T>(_ptr: *const T) {
+    #[cfg(feature = "metrics")]
+    if _ptr.is_null() {
+        crate::metrics::record_empty_read();
+    }
+}
+
+/// A [`MonoSnapshot`] summarises the state of a [`MonoArc`] at one
+/// point in time, as returned by [`MonoArc::snapshot`].
+///
+/// The counts are only a hint: other threads may clone or drop
+/// [`Arc`]s to the same allocation concurrently.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MonoSnapshot {
+    /// Whether the [`MonoArc`] was [`Some`].
+    pub populated: bool,
+    /// The [`Arc::strong_count`] of the stored value (including the
+    /// [`MonoArc`]'s own reference), or 0 when empty.
+    pub strong: usize,
+    /// The [`Arc::weak_count`] of the stored value, or 0 when empty.
+    pub weak: usize,
+}
+
+/// Why [`MonoArc::store_detailed`] rejected a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StoreReject {
+    /// The [`MonoArc`] already held a value.
+    AlreadyPopulated,
+    /// The [`MonoArc`] was [`MonoArc::close`]d.
+    Closed,
+}
+
+/// An [`Observed`] guard keeps the value of a [`MonoArc`] alive for
+/// a scope, and dereferences to that value.  See [`MonoArc::observe`].
+///
+/// The guard owns a strong reference to the value, acquired when the
+/// guard is created and released when it's dropped.
+pub struct Observed<'a, T> {
+    arc: Arc<T>,
+    _mono: core::marker::PhantomData<&'a MonoArc<T>>,
+}
+
+impl<T> core::ops::Deref for Observed<'_, T> {
+    type Target = T;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn deref(&self) -> &T {
+        &self.arc
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Observed<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// A [`Filled<T>`] is a [`MonoArc<T>`] that is known to be
+/// populated, obtained from [`MonoArc::store_filled`].  It owns the
+/// [`Arc`] and dereferences directly to the value, without
+/// re-checking for [`None`] on each read.
+#[derive(Clone)]
+pub struct Filled<T> {
+    arc: Arc<T>,
+}
+
+impl<T> Filled<T> {
+    /// Returns the [`Arc`] owned by this [`Filled`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_arc(self) -> Arc<T> {
+        self.arc
+    }
+}
+
+impl<T> core::ops::Deref for Filled<T> {
+    type Target = T;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn deref(&self) -> &T {
+        &self.arc
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Filled<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// A [`StoreGuard`] reverts a [`MonoArc`] to its previous state when
+/// dropped, unless [`StoreGuard::commit`]ted first.  See
+/// [`MonoArc::store_scoped`].
+pub struct StoreGuard<'a, T> {
+    mono: &'a mut MonoArc<T>,
+    // The value to restore on drop, if we haven't committed.
+    previous: Option<Option<Arc<T>>>,
+}
+
+impl<T> StoreGuard<'_, T> {
+    /// Keeps the scoped value in the [`MonoArc`], and returns the
+    /// value it replaced, if any.
+    pub fn commit(mut self) -> Option<Arc<T>> {
+        self.previous.take().flatten()
+    }
+}
+
+impl<T> core::ops::Deref for StoreGuard<'_, T> {
+    type Target = MonoArc<T>;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn deref(&self) -> &MonoArc<T> {
+        self.mono
+    }
+}
+
+impl<T> Drop for StoreGuard<'_, T> {
+    fn drop(&mut self) {
+        // Rolling back the scoped value isn't the kind of discarded
+        // store `debug-monotonic` looks for.
+        if let Some(previous) = self.previous.take() {
+            core::mem::drop(self.mono.swap_unchecked(previous));
+        }
+    }
+}
+
+impl<T> From<Filled<T>> for MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(filled: Filled<T>) -> MonoArc<T> {
+        MonoArc::from(filled.arc)
+    }
+}
+
+/// A [`ProjectedArc<U>`] points to a `U` inside a value owned by an
+/// [`Arc`], and keeps that [`Arc`] alive.  See
+/// [`MonoArc::project_field`].
+///
+/// The parent's type is erased, so [`ProjectedArc`] can't know
+/// whether it's safe to send or share across threads: it is neither
+/// [`Send`] nor [`Sync`].
+pub struct ProjectedArc<U> {
+    // Type-erased result of `Arc::into_raw` for the parent.
+    parent: *const (),
+    retain: unsafe fn(*const ()),
+    release: unsafe fn(*const ()),
+    ptr: *const U,
+}
+
+/// Increments the strong count of the `Arc<T>` at `parent`.
+unsafe fn retain_erased<T>(parent: *const ()) {
+    Arc::increment_strong_count(parent as *const T);
+}
+
+/// Decrements the strong count of the `Arc<T>` at `parent`.
+unsafe fn release_erased<T>(parent: *const ()) {
+    Arc::decrement_strong_count(parent as *const T);
+}
+
+impl<U> core::ops::Deref for ProjectedArc<U> {
+    type Target = U;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn deref(&self) -> &U {
+        // `ptr` was derived from a shared reference to the parent's
+        // value, which we keep alive.
+        unsafe { &*self.ptr }
+    }
+}
+
+impl<U> Clone for ProjectedArc<U> {
+    fn clone(&self) -> Self {
+        unsafe { (self.retain)(self.parent) };
+
+        ProjectedArc {
+            parent: self.parent,
+            retain: self.retain,
+            release: self.release,
+            ptr: self.ptr,
+        }
+    }
+}
+
+impl<U> Drop for ProjectedArc<U> {
+    fn drop(&mut self) {
+        unsafe { (self.release)(self.parent) };
+    }
+}
+
+impl<U: core::fmt::Debug> core::fmt::Debug for ProjectedArc<U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&**self, f)
+    }
 }
 
 impl<T> MonoArc<T> {
@@ -32,19 +273,40 @@ impl<T> MonoArc<T> {
 
         Self {
             ptr_or_null: AtomicPtr::new(ptr as *mut _),
+            #[cfg(feature = "debug-tracking")]
+            storing_thread: Default::default(),
+            #[cfg(feature = "debug-monotonic")]
+            strict: false,
         }
     }
 
+    /// Returns a fresh empty [`MonoArc`] that enforces write-once
+    /// semantics even under `&mut`: in debug builds, its
+    /// [`MonoArc::swap`] and [`MonoArc::take`] panic instead of
+    /// discarding a value.  Clones of strict [`MonoArc`]s are strict
+    /// as well.
+    ///
+    /// This helps track down code that unexpectedly resets
+    /// containers.  Consuming the container, e.g., with
+    /// [`MonoArc::into_inner`] or by dropping it, is still allowed.
+    #[cfg(feature = "debug-monotonic")]
+    pub fn new_strict() -> Self {
+        let mut mono = Self::empty();
+        mono.strict = true;
+        mono
+    }
+
     /// Returns a fresh [`MonoArc`] that holds [`None`].
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn empty() -> Self {
         Self::new(None)
     }
 
-    /// Returns whether the [`MonoArc`]'s value is [`None`].
+    /// Returns whether the [`MonoArc`]'s value is [`None`].  That's
+    /// true for closed [`MonoArc`]s as well.
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn is_none(&self) -> bool {
-        self.ptr_or_null.load(Ordering::Relaxed).is_null()
+        value_ptr(self.ptr_or_null.load(Ordering::Relaxed)).is_null()
     }
 
     /// Returns whether the [`MonoArc`]'s value is [`Some`].
@@ -53,24 +315,167 @@ impl<T> MonoArc<T> {
         !self.is_none()
     }
 
+    /// Returns whether this [`MonoArc`] has been
+    /// [`MonoArc::close`]d, and will thus never be populated.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn is_closed(&self) -> bool {
+        self.ptr_or_null.load(Ordering::Relaxed) as usize == CLOSED
+    }
+
+    /// Returns whether this [`MonoArc`] and `other` are both
+    /// populated with the same allocation, like [`Arc::ptr_eq`] on
+    /// their values.  Empty or closed [`MonoArc`]s share nothing.
+    ///
+    /// Since populated [`MonoArc`]s are frozen, a true result remains
+    /// true for as long as both containers are borrowed.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn shares_with(&self, other: &MonoArc<T>) -> bool {
+        let ptr = value_ptr(self.ptr_or_null.load(Ordering::Acquire));
+
+        !ptr.is_null() && ptr == value_ptr(other.ptr_or_null.load(Ordering::Acquire))
+    }
+
+    /// Attempts to close this [`MonoArc`], i.e., to freeze it in the
+    /// [`None`] state.  The operation succeeds iff the [`MonoArc`] is
+    /// empty and not already closed.
+    ///
+    /// Returns true on success, and false if the [`MonoArc`] was
+    /// populated or closed.
+    pub fn close(&self) -> bool {
+        self.ptr_or_null
+            .compare_exchange(
+                core::ptr::null_mut(),
+                CLOSED as *mut T,
+                Ordering::Release,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+    }
+
     /// Returns the value previously stored in this [`MonoArc`] and
     /// replaces it with `value`.
+    ///
+    /// Swapping a closed [`MonoArc`] returns [`None`] and reopens
+    /// it.
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn swap(&mut self, value: Option<Arc<T>>) -> Option<Arc<T>> {
+        #[cfg(feature = "debug-monotonic")]
+        debug_assert!(
+            !(self.strict && self.is_some()),
+            "strict MonoArc would discard its value"
+        );
+
+        self.swap_unchecked(value)
+    }
+
+    /// Replaces the contents of this [`MonoArc`] with `value`, until
+    /// the returned guard is dropped: unless the guard is
+    /// [`StoreGuard::commit`]ted, dropping it restores the
+    /// [`MonoArc`]'s previous contents (and drops `value`).  This is
+    /// the [`MonoArc`] counterpart of [`MonoBox::scoped_store`], for
+    /// setup code that must roll back the store if it fails later.
+    ///
+    /// Like [`MonoArc::swap`], this requires exclusive ownership, so
+    /// no other thread can observe the transient state.  Closed
+    /// [`MonoArc`]s are reverted to empty, not closed.
+    pub fn store_scoped(&mut self, value: Arc<T>) -> StoreGuard<'_, T> {
+        let previous = self.swap(Some(value));
+
+        StoreGuard {
+            mono: self,
+            previous: Some(previous),
+        }
+    }
+
+    /// Implements [`MonoArc::swap`], without the `debug-monotonic`
+    /// check.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn swap_unchecked(&mut self, value: Option<Arc<T>>) -> Option<Arc<T>> {
         let new = value.map(Arc::into_raw).unwrap_or_else(core::ptr::null);
-        // We should be able to use `Relaxed` loads and store here,
-        // and rely on the ordering that guarantees `self` is `&mut`.
-        // However, it's more obviously safe when every load and store
-        // can be matched as acquires and releases.
-        let old = self.ptr_or_null.load(Ordering::Acquire);
+        // `Relaxed` is enough: whatever handed us a `&mut` reference
+        // (a join, a channel, a lock, ...) already made every earlier
+        // store happen-before this call, and will make our store
+        // happen-before any later access from another thread.
+        let old = self.ptr_or_null.load(Ordering::Relaxed);
 
         // We don't need or want an atomic swap here: `&mut`
         // guarantees exclusive ownership.
-        self.ptr_or_null.store(new as *mut T, Ordering::Release);
+        self.ptr_or_null.store(new as *mut T, Ordering::Relaxed);
+        let old = value_ptr(old);
         if old.is_null() {
             None
         } else {
-            Some(unsafe { Arc::from_raw(old as *const T) })
+            Some(unsafe { Arc::from_raw(old) })
+        }
+    }
+
+    /// Returns a mutable reference to the value stored in this
+    /// [`MonoArc`], or [`None`] if the [`MonoArc`] is empty (or
+    /// closed).
+    ///
+    /// This is [`Arc::make_mut`] for the stored [`Arc`]: when the
+    /// [`MonoArc`] holds the only strong reference to its value, we
+    /// return a reference to that value directly.  Otherwise, we
+    /// clone the value into a fresh [`Arc`], replace the stored
+    /// [`Arc`] with it, and return a reference to the clone; other
+    /// [`Arc`]s keep pointing to the original value.  If only
+    /// [`Weak`] references remain, the value is moved to a fresh
+    /// allocation instead of cloned, and the [`Weak`]s can no longer
+    /// upgrade.
+    pub fn get_mut_or_clone(&mut self) -> Option<&mut T>
+    where
+        T: Clone,
+    {
+        // `Relaxed` is enough, as in `swap_unchecked`: `&mut self`
+        // already orders us after every other access.
+        let ptr = value_ptr(self.ptr_or_null.load(Ordering::Relaxed));
+        if ptr.is_null() {
+            return None;
+        }
+
+        // Borrow the `MonoArc`'s reference in place: if `T::clone`
+        // panics, `make_mut` leaves the `Arc`, and thus the
+        // `MonoArc`, untouched.
+        let mut arc = core::mem::ManuallyDrop::new(unsafe { Arc::from_raw(ptr) });
+        let value: *mut T = Arc::make_mut(&mut arc);
+        self.ptr_or_null.store(
+            Arc::into_raw(core::mem::ManuallyDrop::into_inner(arc)) as *mut T,
+            Ordering::Relaxed,
+        );
+        // `&mut self` keeps the `Arc` unique until the reference
+        // dies.
+        Some(unsafe { &mut *value })
+    }
+
+    /// Replaces the value stored in this [`MonoArc`] with `value`,
+    /// and returns both the previous value, if any, and a clone of
+    /// `value`.
+    pub fn replace(&mut self, value: Arc<T>) -> (Option<Arc<T>>, Arc<T>) {
+        let installed = value.clone();
+
+        (self.swap(Some(value)), installed)
+    }
+
+    /// Replaces the value stored in this [`MonoArc`] with `value`,
+    /// like [`MonoArc::swap`], but only if the [`MonoArc`] currently
+    /// holds the allocation at `expected` (or is empty, when
+    /// `expected` is null).
+    ///
+    /// Returns the previous contents as [`Ok`] when the pointers
+    /// match, and passes back `value` as [`Err`] otherwise.  Since
+    /// `&mut` guarantees exclusive ownership, there is no race here:
+    /// the identity check protects against logic errors, e.g.,
+    /// replacing a value other than the one previously observed with
+    /// [`MonoArc::get`].
+    pub fn swap_if_ptr(
+        &mut self,
+        expected: *const T,
+        value: Option<Arc<T>>,
+    ) -> Result<Option<Arc<T>>, Option<Arc<T>>> {
+        if value_ptr(self.ptr_or_null.load(Ordering::Acquire)) == expected {
+            Ok(self.swap(value))
+        } else {
+            Err(value)
         }
     }
 
@@ -79,7 +484,13 @@ impl<T> MonoArc<T> {
     /// [`Some`].
     ///
     /// Returns [`Ok`] when the store succeeds, and passes back
-    /// `value` as [`Err`] otherwise.
+    /// `value` as [`Err`] otherwise, including when the [`MonoArc`] is
+    /// closed.
+    ///
+    /// The store only moves the [`Arc`]'s pointer, never the value.
+    /// Large values can be built in place, without a stack copy, by
+    /// initialising an [`Arc::new_uninit`] and storing the result of
+    /// [`Arc::assume_init`].
     pub fn store(&self, value: Arc<T>) -> Result<(), Arc<T>> {
         let ptr = Arc::into_raw(value);
 
@@ -89,11 +500,141 @@ impl<T> MonoArc<T> {
             Ordering::Release,
             Ordering::Relaxed,
         ) {
-            Ok(_) => Ok(()),
-            Err(_) => Err(unsafe { Arc::from_raw(ptr) }),
+            Ok(_) => {
+                self.note_store();
+                Ok(())
+            }
+            Err(_) => {
+                note_contended();
+                Err(unsafe { Arc::from_raw(ptr) })
+            }
+        }
+    }
+
+    /// Records the current thread as the winner of the first
+    /// successful store, when the `debug-tracking` feature is
+    /// enabled.  A no-op otherwise.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn note_store(&self) {
+        #[cfg(feature = "debug-tracking")]
+        let _ = self.storing_thread.set(std::thread::current().id());
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_store(true);
+    }
+
+    /// Returns the [`ThreadId`](std::thread::ThreadId) of the thread
+    /// that first successfully stored a value in this [`MonoArc`], if
+    /// any.
+    ///
+    /// The thread is recorded right after the store, so readers may
+    /// briefly observe a stored value before its thread.  Values
+    /// installed by constructors or [`MonoArc::swap`] aren't tracked.
+    #[cfg(feature = "debug-tracking")]
+    pub fn storing_thread(&self) -> Option<std::thread::ThreadId> {
+        self.storing_thread.get().copied()
+    }
+
+    /// Attempts to store `value` in this [`MonoArc`], like
+    /// [`MonoArc::store`], but reports why the store failed.
+    ///
+    /// Returns [`Ok`] when the store succeeds, and passes back
+    /// `value` along with a [`StoreReject`] reason as [`Err`]
+    /// otherwise.
+    pub fn store_detailed(&self, value: Arc<T>) -> Result<(), (Arc<T>, StoreReject)> {
+        let ptr = Arc::into_raw(value);
+
+        match self.ptr_or_null.compare_exchange(
+            core::ptr::null_mut(),
+            ptr as *mut _,
+            Ordering::Release,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => {
+                self.note_store();
+                Ok(())
+            }
+            Err(actual) => {
+                note_contended();
+                let reason = if actual as usize == CLOSED {
+                    StoreReject::Closed
+                } else {
+                    StoreReject::AlreadyPopulated
+                };
+
+                Err((unsafe { Arc::from_raw(ptr) }, reason))
+            }
+        }
+    }
+
+    /// Attempts to store `value` in this [`MonoArc`], like
+    /// [`MonoArc::store`], but also succeeds when the [`MonoArc`]
+    /// already holds a value equal to `value`.  This simplifies
+    /// initialisation patterns where every thread computes the same
+    /// value.
+    ///
+    /// Returns [`Ok`] when `value` was stored or equals the current
+    /// value (in which case `value` is dropped), and passes back
+    /// `value` as [`Err`] otherwise, including when the [`MonoArc`]
+    /// is closed.
+    pub fn store_idempotent(&self, value: Arc<T>) -> Result<(), Arc<T>>
+    where
+        T: PartialEq,
+    {
+        match self.store(value) {
+            Ok(()) => Ok(()),
+            Err(value) if self.as_ref() == Some(&*value) => Ok(()),
+            Err(value) => Err(value),
         }
     }
 
+    /// Attempts to store `value` in this [`MonoArc`], and then
+    /// stores whichever [`Arc`] this [`MonoArc`] ends up holding (the
+    /// canonical value) in `mirror` as well.  Returns the canonical
+    /// value.
+    ///
+    /// When every writer goes through this method, both cells end up
+    /// holding the same allocation.  If `mirror` was already
+    /// populated by other means, it keeps its value.  If this
+    /// [`MonoArc`] is closed, `value` is canonical.
+    pub fn store_and_mirror(&self, value: Arc<T>, mirror: &MonoArc<T>) -> Arc<T> {
+        let rejected = self.store(value).err();
+        let canonical = self
+            .get()
+            .or(rejected)
+            .expect("MonoArc must be populated or closed after a store");
+
+        let _ = mirror.store(canonical.clone());
+        canonical
+    }
+
+    /// Attempts to upgrade `weak` and store the resulting [`Arc`] in
+    /// this [`MonoArc`].
+    ///
+    /// Returns [`Ok`] when the store succeeds, and [`Err`] when
+    /// `weak` is dead, or when the [`MonoArc`] is already populated
+    /// (or closed).
+    #[allow(clippy::result_unit_err)]
+    pub fn store_weak_upgraded(&self, weak: Weak<T>) -> Result<(), ()> {
+        let arc = weak.upgrade().ok_or(())?;
+
+        self.store(arc).map_err(|_| ())
+    }
+
+    /// Attempts to store `value` in this [`MonoArc`], like
+    /// [`MonoArc::store`], and returns a [`Weak`] reference to the
+    /// stored value on success.  This helps wire up back-references
+    /// in cyclic structures.
+    ///
+    /// Returns the [`Weak`] when the store succeeds, and passes back
+    /// `value` as [`Err`] otherwise.
+    pub fn store_and_downgrade(&self, value: Arc<T>) -> Result<Weak<T>, Arc<T>> {
+        // Downgrade while we still own `value`; the `Weak` is simply
+        // dropped if the store fails.
+        let weak = Arc::downgrade(&value);
+
+        self.store(value).map(|()| weak)
+    }
+
     /// Attempts to store `value` in this [`MonoArc`].
     ///
     /// Returns true on success and false if there already was some
@@ -102,17 +643,85 @@ impl<T> MonoArc<T> {
         self.store(Arc::new(value)).is_ok()
     }
 
+    /// Attempts to store the first item from `iter` in this
+    /// [`MonoArc`], and ignores the rest of the iterator.
+    ///
+    /// Returns true if that item populated the [`MonoArc`], and false
+    /// if `iter` is empty or the [`MonoArc`] was already populated or
+    /// closed.  The iterator is advanced at most once, and not at all
+    /// if the [`MonoArc`] is already populated or closed when we
+    /// start.
+    pub fn store_first(&self, iter: impl IntoIterator<Item = T>) -> bool {
+        if !self.ptr_or_null.load(Ordering::Relaxed).is_null() {
+            return false;
+        }
+
+        match iter.into_iter().next() {
+            Some(value) => self.store_value(value),
+            None => false,
+        }
+    }
+
+    /// Consumes this [`MonoArc`] and attempts to store `value` in it.
+    ///
+    /// Returns a [`Filled`] that owns `value` when the [`MonoArc`]
+    /// was empty, and passes back the [`MonoArc`] along with `value`
+    /// as [`Err`] when it was already populated or closed.
+    pub fn store_filled(self, value: Arc<T>) -> Result<Filled<T>, (MonoArc<T>, Arc<T>)> {
+        match self.store(value) {
+            Ok(()) => Ok(Filled {
+                arc: self
+                    .into_inner()
+                    .expect("MonoArc must be populated after a store"),
+            }),
+            Err(value) => Err((self, value)),
+        }
+    }
+
+    /// Gets a clone of the [`Arc`] stored in this [`MonoArc`], or
+    /// attempts to populate the [`MonoArc`] with the result of `f` if
+    /// it's empty, and returns a clone of whichever [`Arc`] ended up
+    /// in the container.
+    ///
+    /// Initialisation isn't serialised: concurrent callers that find
+    /// the [`MonoArc`] empty may all run their `f`, but they all
+    /// return the same allocation, and losers' [`Arc`]s are dropped.
+    /// Closed [`MonoArc`]s can't be populated, so we then return the
+    /// result of `f` as is.
+    pub fn get_or_init_arc(&self, f: impl FnOnce() -> Arc<T>) -> Arc<T> {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        match self.store(f()) {
+            Ok(()) => self.get().expect("MonoArc must be populated after a store"),
+            // If we lost the race, drop our value and use the winner's.
+            Err(value) => self.get().unwrap_or(value),
+        }
+    }
+
     /// Gets the value stored in this [`MonoArc`], if any.
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn as_ref(&self) -> Option<&T> {
-        let ptr = self.ptr_or_null.load(Ordering::Acquire);
+        let ptr = value_ptr(self.ptr_or_null.load(Ordering::Acquire));
+        note_read(ptr);
         unsafe { ptr.as_ref() }
     }
 
+    /// Gets the value stored in this [`MonoArc`], if any, along with
+    /// its address, from a single load.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn as_ref_and_ptr(&self) -> Option<(&T, *const T)> {
+        let ptr: *const T = value_ptr(self.ptr_or_null.load(Ordering::Acquire));
+        note_read(ptr);
+        unsafe { ptr.as_ref() }.map(|value| (value, ptr))
+    }
+
     /// Gets a clone of the [`Arc`] stored in this [`MonoArc`], if any.
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn get(&self) -> Option<Arc<T>> {
-        let ptr = self.ptr_or_null.load(Ordering::Acquire) as *const T;
+        let ptr = value_ptr(self.ptr_or_null.load(Ordering::Acquire));
+        note_read(ptr);
 
         if ptr.is_null() {
             None
@@ -124,91 +733,519 @@ impl<T> MonoArc<T> {
         }
     }
 
-    /// Takes the value out of this [`MonoArc`], leaving a [`None`] in
-    /// its place.
+    /// Returns a [`Weak`] reference to the value stored in this
+    /// [`MonoArc`], if any, without touching the strong count.
+    pub fn as_weak(&self) -> Option<Weak<T>> {
+        let ptr = value_ptr(self.ptr_or_null.load(Ordering::Acquire));
+
+        if ptr.is_null() {
+            return None;
+        }
+
+        // Borrow the `MonoArc`'s reference: `ManuallyDrop` makes sure
+        // we don't decrement the strong count on our way out.
+        let arc = core::mem::ManuallyDrop::new(unsafe { Arc::from_raw(ptr) });
+        Some(Arc::downgrade(&arc))
+    }
+
+    /// Gets a clone of the [`Arc`] stored in this [`MonoArc`], if
+    /// any, as a [`Pin<Arc<T>>`](core::pin::Pin).
+    ///
+    /// # Safety
+    ///
+    /// The [`Arc`]'s pointee never moves while shared, but this
+    /// [`MonoArc`] (and clones of the [`Arc`]) still hand out
+    /// unpinned access to the value.  Unless `T: Unpin`, the caller
+    /// must ensure the value is never moved out of its allocation
+    /// (e.g., with [`Arc::try_unwrap`] or
+    /// [`MonoArc::get_mut_or_clone`]) until it is dropped.
     #[cfg_attr(not(tarpaulin), inline(always))]
-    pub fn take(&mut self) -> Option<Arc<T>> {
-        self.swap(None)
+    pub unsafe fn get_pinned(&self) -> Option<core::pin::Pin<Arc<T>>> {
+        self.get().map(|arc| core::pin::Pin::new_unchecked(arc))
     }
 
-    /// Consumes this [`MonoArc`], returning the wrapped value, if any.
+    /// Gets a clone of the [`Arc`] stored in this [`MonoArc`],
+    /// busy-waiting for up to `max_spins` iterations of
+    /// [`core::hint::spin_loop`] if the [`MonoArc`] is empty.
+    ///
+    /// The [`MonoArc`] is polled with exponential backoff, so the
+    /// spins are spread out over increasingly long intervals.
+    /// Returns [`None`] if the [`MonoArc`] is still empty after
+    /// `max_spins` spins.
+    pub fn get_spin(&self, max_spins: u32) -> Option<Arc<T>> {
+        const MAX_BACKOFF: u32 = 64;
+
+        let mut remaining = max_spins;
+        let mut backoff = 1;
+        loop {
+            if let Some(arc) = self.get() {
+                return Some(arc);
+            }
+
+            if remaining == 0 {
+                return None;
+            }
+
+            let spins = backoff.min(remaining);
+            for _ in 0..spins {
+                core::hint::spin_loop();
+            }
+
+            remaining -= spins;
+            backoff = (2 * backoff).min(MAX_BACKOFF);
+        }
+    }
+
+    /// Returns a copyable [`Guard`] that re-reads this [`MonoArc`]
+    /// on every [`Guard::get`] call.
     #[cfg_attr(not(tarpaulin), inline(always))]
-    pub fn into_inner(mut self) -> Option<Arc<T>> {
-        self.take()
+    pub fn guard(&self) -> Guard<'_, T> {
+        Guard::new(self)
     }
-}
 
-impl<T> Drop for MonoArc<T> {
-    fn drop(&mut self) {
-        core::mem::drop(self.take());
+    /// Returns a [`ProjectedArc`] that dereferences to the part of
+    /// this [`MonoArc`]'s value selected by `f` (e.g., a field), and
+    /// keeps the whole value alive, or [`None`] if the [`MonoArc`] is
+    /// empty.
+    ///
+    /// The projection shares the [`Arc`]'s strong count, and thus
+    /// remains valid after the [`MonoArc`] is dropped.  Since
+    /// [`ProjectedArc`] erases `T`, and may thus drop the value long
+    /// after any lifetime in `T` has expired, `T` must be `'static`.
+    pub fn project_field<U>(&self, f: impl FnOnce(&T) -> &U) -> Option<ProjectedArc<U>>
+    where
+        T: 'static,
+    {
+        // Project before `into_raw`, so a panic in `f` drops `arc`
+        // instead of leaking a strong reference.
+        let arc = self.get()?;
+        let ptr: *const U = f(&arc);
+        let parent = Arc::into_raw(arc);
+
+        Some(ProjectedArc {
+            parent: parent as *const (),
+            retain: retain_erased::<T>,
+            release: release_erased::<T>,
+            ptr,
+        })
     }
-}
 
-impl<T> Default for MonoArc<T> {
+    /// Returns a guard that keeps the value stored in this
+    /// [`MonoArc`] alive and dereferences to it, or [`None`] if the
+    /// [`MonoArc`] is empty.
+    ///
+    /// This is like [`MonoArc::get`], except that the guard is tied
+    /// to the [`MonoArc`]'s lifetime.  The guard owns a strong
+    /// reference, so the value survives even if every other handle
+    /// on the allocation (e.g., clones of the [`MonoArc`] that get
+    /// [`MonoArc::swap`]ped or [`MonoArc::take`]n on other threads)
+    /// lets go of it.
     #[cfg_attr(not(tarpaulin), inline(always))]
-    fn default() -> Self {
-        Self::empty()
+    pub fn observe(&self) -> Option<Observed<'_, T>> {
+        self.get().map(|arc| Observed {
+            arc,
+            _mono: core::marker::PhantomData,
+        })
     }
-}
 
-impl<T> Clone for MonoArc<T> {
-    fn clone(&self) -> MonoArc<T> {
-        let ptr = self.ptr_or_null.load(Ordering::Acquire);
+    /// Returns whether this [`MonoArc`] is populated, along with the
+    /// stored value's reference counts, without cloning the [`Arc`].
+    ///
+    /// Empty (or closed) [`MonoArc`]s return an all-zero
+    /// [`MonoSnapshot`].
+    pub fn snapshot(&self) -> MonoSnapshot {
+        let ptr = value_ptr(self.ptr_or_null.load(Ordering::Acquire));
 
-        if !ptr.is_null() {
-            unsafe { Arc::increment_strong_count(ptr as *const T) };
+        if ptr.is_null() {
+            return MonoSnapshot::default();
         }
 
-        MonoArc {
-            ptr_or_null: AtomicPtr::new(ptr),
+        // Borrow the `MonoArc`'s reference: `ManuallyDrop` makes sure
+        // we don't decrement the strong count on our way out.
+        let arc = core::mem::ManuallyDrop::new(unsafe { Arc::from_raw(ptr) });
+        MonoSnapshot {
+            populated: true,
+            strong: Arc::strong_count(&arc),
+            weak: Arc::weak_count(&arc),
         }
     }
-}
 
-impl<T: core::fmt::Debug> core::fmt::Debug for MonoArc<T> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        core::fmt::Debug::fmt(&self.as_ref(), f)
+    /// Returns whether this [`MonoArc`] holds the only reference to
+    /// its value: [`Some(true)`](Some) if there are no other
+    /// [`Arc`] or [`Weak`] references, [`Some(false)`](Some) if the
+    /// value is shared, and [`None`] if the [`MonoArc`] is empty.
+    ///
+    /// Like [`MonoArc::snapshot`], this is only a hint when other
+    /// threads may hold references to the [`MonoArc`].
+    pub fn is_unique(&self) -> Option<bool> {
+        let snapshot = self.snapshot();
+
+        if snapshot.populated {
+            Some(snapshot.strong == 1 && snapshot.weak == 0)
+        } else {
+            None
+        }
     }
-}
 
-impl<T> core::fmt::Pointer for MonoArc<T> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        core::fmt::Pointer::fmt(&(self.ptr_or_null.load(Ordering::Relaxed) as *const T), f)
+    /// Calls `f` with the pointer to the value stored in this
+    /// [`MonoArc`], or with a null pointer if the [`MonoArc`] is
+    /// empty (or closed), and returns `f`'s result.
+    ///
+    /// This gives access to the value's address without creating a
+    /// reference to the value itself.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn with_raw<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+        f(value_ptr(self.ptr_or_null.load(Ordering::Acquire)))
     }
-}
 
-impl<T: core::ops::Deref> MonoArc<T> {
+    /// Returns the raw state of this [`MonoArc`], read with a
+    /// `Relaxed` load, for logging.  The address is the raw pointer
+    /// field, so a closed [`MonoArc`] reports the sentinel address,
+    /// but isn't `populated`.
+    pub fn debug_state(&self) -> ContainerDebug {
+        let ptr = self.ptr_or_null.load(Ordering::Relaxed);
+
+        ContainerDebug {
+            ptr_addr: ptr as usize,
+            populated: !value_ptr(ptr).is_null(),
+        }
+    }
+
+    /// Calls `f` with the value stored in this [`MonoArc`], if any,
+    /// and returns `f`'s result.
+    ///
+    /// This is cheaper than [`MonoArc::get`] for read-and-discard
+    /// patterns: the value is borrowed from the [`MonoArc`], so its
+    /// reference count is never touched.
     #[cfg_attr(not(tarpaulin), inline(always))]
-    pub fn as_deref(&self) -> Option<&T::Target> {
-        self.as_ref().map(|t| t.deref())
+    pub fn with<R>(&self, f: impl FnOnce(Option<&T>) -> R) -> R {
+        f(self.as_ref())
     }
-}
 
-impl<'a, T> From<&'a MonoArc<T>> for Option<&'a T> {
+    /// Applies `f` to the value stored in this [`MonoArc`], if any,
+    /// and returns the result; returns [`None`] if the [`MonoArc`] is
+    /// empty (or closed).  This is [`Option::map`] over
+    /// [`MonoArc::as_ref`], without cloning the [`Arc`].
     #[cfg_attr(not(tarpaulin), inline(always))]
-    fn from(mono: &'a MonoArc<T>) -> Option<&T> {
-        mono.as_ref()
+    pub fn map<U>(&self, f: impl FnOnce(&T) -> U) -> Option<U> {
+        self.as_ref().map(f)
     }
-}
 
-impl<T> From<T> for MonoArc<T> {
-    fn from(value: T) -> MonoArc<T> {
-        MonoArc::new(Some(Arc::new(value)))
+    /// Converts a [`MonoBox<T>`] into a [`MonoArc<T>`] that holds the
+    /// same value, if any.
+    ///
+    /// The value must be moved to a fresh allocation: an [`Arc`]'s
+    /// allocation starts with its reference counts, so there's no
+    /// way to adopt a [`Box`](alloc::boxed::Box)'s allocation in
+    /// place.  The move is a bitwise copy of `T`, followed by a
+    /// deallocation of the original box.
+    pub fn from_box_moving(mono: MonoBox<T>) -> MonoArc<T> {
+        MonoArc::new(mono.into_inner().map(Arc::from))
     }
-}
 
-impl<T> From<Option<T>> for MonoArc<T> {
-    fn from(value: Option<T>) -> MonoArc<T> {
-        MonoArc::new(value.map(Arc::new))
+    /// Replaces the contents of `dst` with a clone of this
+    /// [`MonoArc`]'s [`Arc`], or with [`None`] if this [`MonoArc`] is
+    /// empty.  This reuses `dst` instead of allocating a new
+    /// container, and drops `dst`'s previous value, if any.
+    pub fn clone_into(&self, dst: &mut MonoArc<T>) {
+        core::mem::drop(dst.swap(self.get()));
     }
-}
 
-impl<T> From<Arc<T>> for MonoArc<T> {
+    /// Returns a clone of this [`MonoArc`], sharing its value, if the
+    /// [`MonoArc`] is populated, and [`None`] if it's empty (or
+    /// closed).
     #[cfg_attr(not(tarpaulin), inline(always))]
-    fn from(value: Arc<T>) -> MonoArc<T> {
-        MonoArc::new(Some(value))
+    pub fn clone_if_some(&self) -> Option<MonoArc<T>> {
+        self.get().map(MonoArc::from)
     }
-}
+
+    /// Returns a clone of this [`MonoArc`], like [`Clone::clone`],
+    /// but reads the pointer with a `Relaxed` load instead of an
+    /// `Acquire` load.  This is marginally cheaper on
+    /// weakly-ordered architectures.
+    ///
+    /// # Safety
+    ///
+    /// The clone increments the stored [`Arc`]'s strong count, so the
+    /// caller must ensure that the store of the current value (if
+    /// any) happens-before this call, e.g., because the value was
+    /// stored by the current thread, or because this [`MonoArc`] was
+    /// already observed as populated with an `Acquire` load.
+    pub unsafe fn clone_relaxed(&self) -> MonoArc<T> {
+        self.clone_with(Ordering::Relaxed)
+    }
+
+    /// Returns a clone of this [`MonoArc`], after reading the pointer
+    /// with `order`.
+    ///
+    /// The caller must ensure `order` synchronises with the store of
+    /// the current value, if any.
+    unsafe fn clone_with(&self, order: core::sync::atomic::Ordering) -> MonoArc<T> {
+        // Clones of closed `MonoArc`s are closed as well.
+        let ptr = self.ptr_or_null.load(order);
+        let value = value_ptr(ptr);
+
+        if !value.is_null() {
+            Arc::increment_strong_count(value);
+        }
+
+        MonoArc {
+            ptr_or_null: AtomicPtr::new(ptr),
+            #[cfg(feature = "debug-tracking")]
+            storing_thread: self.storing_thread.clone(),
+            #[cfg(feature = "debug-monotonic")]
+            strict: self.strict,
+        }
+    }
+
+    /// Takes the value out of this [`MonoArc`], leaving a [`None`] in
+    /// its place.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn take(&mut self) -> Option<Arc<T>> {
+        self.swap(None)
+    }
+
+    /// Consumes this [`MonoArc`], returning the wrapped value, if any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_inner(mut self) -> Option<Arc<T>> {
+        self.swap_unchecked(None)
+    }
+
+    /// Consumes this [`MonoArc`], returning the wrapped [`Arc`], if
+    /// any.
+    ///
+    /// Unlike [`MonoArc::into_inner`], this doesn't write a null
+    /// pointer back to the container: the returned [`Arc`] inherits
+    /// the [`MonoArc`]'s strong reference, and the container is
+    /// forgotten instead of dropped.
+    pub fn into_arc(self) -> Option<Arc<T>> {
+        // Owning `self` excludes concurrent writers, so `Relaxed` is
+        // enough, as in `swap()`.
+        let this = core::mem::ManuallyDrop::new(self);
+        let ptr = value_ptr(this.ptr_or_null.load(Ordering::Relaxed));
+
+        // Drop the other fields by hand, since we skip `Drop`.
+        #[cfg(feature = "debug-tracking")]
+        core::mem::drop(unsafe { core::ptr::read(&this.storing_thread) });
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Arc::from_raw(ptr) })
+        }
+    }
+
+    /// Consumes this [`MonoArc<T>`], and returns a [`MonoArc<U>`] that
+    /// owns the same allocation, without moving or copying the value.
+    /// Closed [`MonoArc`]s stay closed.
+    ///
+    /// # Safety
+    ///
+    /// This has the same requirements as casting an
+    /// [`Arc::into_raw`] pointer from `*const T` to `*const U` and
+    /// passing it to [`Arc::from_raw`]: `U` must have the same size
+    /// and alignment as `T`, and the value must be a valid `U` (e.g.,
+    /// `U` is a `#[repr(transparent)]` newtype around `T`).  Other
+    /// [`Arc`]s that share the value keep accessing it as a `T`, and
+    /// the value will be dropped as whichever type releases the last
+    /// strong reference.
+    pub unsafe fn reinterpret<U>(self) -> MonoArc<U> {
+        debug_assert_eq!(core::mem::size_of::<T>(), core::mem::size_of::<U>());
+        debug_assert_eq!(core::mem::align_of::<T>(), core::mem::align_of::<U>());
+
+        // Owning `self` excludes concurrent writers, so `Relaxed` is
+        // enough, as in `swap()`.
+        let this = core::mem::ManuallyDrop::new(self);
+        let ptr = this.ptr_or_null.load(Ordering::Relaxed);
+
+        MonoArc {
+            ptr_or_null: AtomicPtr::new(ptr as *mut U),
+            #[cfg(feature = "debug-tracking")]
+            storing_thread: core::ptr::read(&this.storing_thread),
+            #[cfg(feature = "debug-monotonic")]
+            strict: this.strict,
+        }
+    }
+}
+
+/// Returns whether every [`MonoArc`] in `cells` is populated.
+///
+/// Since populated [`MonoArc`]s stay populated, a true result remains
+/// true for as long as `cells` is borrowed.
+pub fn all_populated<T>(cells: &[MonoArc<T>]) -> bool {
+    cells.iter().all(MonoArc::is_some)
+}
+
+/// Stores each of `values` in the first empty [`MonoArc`] of `cells`
+/// that comes after the previous value's cell, skipping populated
+/// (or closed) cells.  When a store loses a race, the value moves on
+/// to the next empty cell.
+///
+/// Stops once `values` or `cells` are exhausted; any remaining
+/// values are dropped without being stored.
+pub fn fill_from<T, I: IntoIterator<Item = Arc<T>>>(cells: &[MonoArc<T>], values: I) {
+    let mut cells = cells.iter();
+
+    for mut value in values {
+        loop {
+            let Some(cell) = cells.next() else {
+                return;
+            };
+
+            match cell.store(value) {
+                Ok(()) => break,
+                Err(rejected) => value = rejected,
+            }
+        }
+    }
+}
+
+/// Drops every [`MonoArc`] in `cells`, in one pass.
+///
+/// Each populated [`MonoArc`] still releases its own strong
+/// reference, but we skip the per-container store of a null pointer
+/// that [`Drop`] would perform.
+pub fn drop_all<T>(cells: alloc::vec::Vec<MonoArc<T>>) {
+    for cell in cells {
+        core::mem::drop(cell.into_arc());
+    }
+}
+
+/// Returns a clone of every [`MonoArc`]'s [`Arc`] in `cells` if they
+/// are all populated, and [`None`] if any is empty.
+///
+/// The returned [`Arc`]s can then be read without checking for
+/// [`None`].
+pub fn try_freeze_all<T>(cells: &[MonoArc<T>]) -> Option<alloc::vec::Vec<Arc<T>>> {
+    cells.iter().map(MonoArc::get).collect()
+}
+
+#[cfg(feature = "std")]
+impl<U> MonoArc<std::sync::Mutex<U>> {
+    /// Locks the [`Mutex`](std::sync::Mutex) stored in this
+    /// [`MonoArc`], if any, and returns [`None`] if the [`MonoArc`]
+    /// is empty (or closed).  This is `mono.as_ref()?.lock()`, for
+    /// write-once containers of late-mutable values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Mutex`](std::sync::Mutex) is poisoned.
+    pub fn lock(&self) -> Option<std::sync::MutexGuard<'_, U>> {
+        Some(self.as_ref()?.lock().expect("MonoArc's Mutex is poisoned"))
+    }
+}
+
+impl<T> Drop for MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn drop(&mut self) {
+        // Large tables of empty containers are common: bail with a
+        // plain `Relaxed` load (`&mut` excludes concurrent writers)
+        // before going through `swap_unchecked()`.
+        if self.is_none() {
+            return;
+        }
+
+        core::mem::drop(self.swap_unchecked(None));
+    }
+}
+
+impl<T> Default for MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> Clone for MonoArc<T> {
+    fn clone(&self) -> MonoArc<T> {
+        unsafe { self.clone_with(Ordering::Acquire) }
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for MonoArc<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.as_ref(), f)
+    }
+}
+
+/// A populated [`MonoArc<T>`] compares equal to a `T` iff its value
+/// does; an empty [`MonoArc`] is never equal to any `T`.
+impl<T: PartialEq> PartialEq<T> for MonoArc<T> {
+    fn eq(&self, other: &T) -> bool {
+        self.as_ref() == Some(other)
+    }
+}
+
+/// [`MonoArc`]s compare like the [`Option<&T>`] returned by
+/// [`MonoArc::as_ref`]: closed [`MonoArc`]s are equal to empty ones.
+/// Storing to an empty [`MonoArc`] changes its equality (and hash),
+/// so empty [`MonoArc`]s used as map keys must not be populated while
+/// in the map.
+impl<T: PartialEq> PartialEq for MonoArc<T> {
+    fn eq(&self, other: &MonoArc<T>) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T: Eq> Eq for MonoArc<T> {}
+
+/// [`MonoArc`]s hash like the [`Option<&T>`] returned by
+/// [`MonoArc::as_ref`].
+impl<T: core::hash::Hash> core::hash::Hash for MonoArc<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+impl<T> core::fmt::Pointer for MonoArc<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Pointer::fmt(&value_ptr(self.ptr_or_null.load(Ordering::Relaxed)), f)
+    }
+}
+
+impl<T: core::ops::Deref> MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn as_deref(&self) -> Option<&T::Target> {
+        self.as_ref().map(|t| t.deref())
+    }
+}
+
+impl<'a, T> From<&'a MonoArc<T>> for Option<&'a T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(mono: &'a MonoArc<T>) -> Option<&'a T> {
+        mono.as_ref()
+    }
+}
+
+impl<T> From<T> for MonoArc<T> {
+    fn from(value: T) -> MonoArc<T> {
+        MonoArc::new(Some(Arc::new(value)))
+    }
+}
+
+impl<T> From<Option<T>> for MonoArc<T> {
+    fn from(value: Option<T>) -> MonoArc<T> {
+        MonoArc::new(value.map(Arc::new))
+    }
+}
+
+impl<T> From<Arc<T>> for MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(value: Arc<T>) -> MonoArc<T> {
+        MonoArc::new(Some(value))
+    }
+}
+
+/// Moves the boxed value to a fresh [`Arc`] allocation, like
+/// [`Arc::from`]: an [`Arc`] can't adopt a [`Box`](alloc::boxed::Box)'s
+/// allocation, since it must start with the reference counts.  See
+/// [`MonoArc::from_box_moving`].
+impl<T> From<alloc::boxed::Box<T>> for MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(value: alloc::boxed::Box<T>) -> MonoArc<T> {
+        MonoArc::new(Some(Arc::from(value)))
+    }
+}
 
 impl<T> From<Option<Arc<T>>> for MonoArc<T> {
     #[cfg_attr(not(tarpaulin), inline(always))]
@@ -225,8 +1262,9 @@ impl<T> From<MonoArc<T>> for Option<Arc<T>> {
 }
 
 impl<T> From<MonoBox<T>> for MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
     fn from(mono: MonoBox<T>) -> MonoArc<T> {
-        MonoArc::new(mono.into_inner().map(Into::into))
+        MonoArc::from_box_moving(mono)
     }
 }
 
@@ -309,6 +1347,83 @@ fn test_drop() {
     assert_eq!(counter.load(Ordering::Relaxed), 2);
 }
 
+#[test]
+fn test_external_weak() {
+    let arc = Arc::new(vec![1usize]);
+    let weak = Arc::downgrade(&arc);
+
+    let mono = MonoArc::<Vec<usize>>::empty();
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+    assert_eq!(Arc::strong_count(&arc), 2);
+    assert_eq!(Arc::weak_count(&arc), 1);
+
+    // The container's reference keeps the allocation alive after the
+    // external strong references are gone...
+    std::mem::drop(arc);
+    assert_eq!(weak.strong_count(), 1);
+    assert_eq!(weak.upgrade().as_deref(), Some(&vec![1]));
+    assert_eq!(mono.snapshot().weak, 1);
+
+    // ... and the container's `Drop` releases exactly one strong
+    // reference, leaving the `Weak` dangling.
+    std::mem::drop(mono);
+    assert_eq!(weak.strong_count(), 0);
+    assert_eq!(weak.weak_count(), 0);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn test_weak_cycle() {
+    use std::sync::atomic::AtomicUsize;
+
+    // A parent holds its child in a `MonoArc`, and the child points
+    // back to its parent with a `Weak`.
+    struct Node<'a> {
+        counter: &'a AtomicUsize,
+        parent: MonoBox<Weak<Node<'a>>>,
+        child: MonoArc<Node<'a>>,
+    }
+
+    impl Drop for Node<'_> {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = AtomicUsize::new(0);
+    let node = || Node {
+        counter: &counter,
+        parent: MonoBox::empty(),
+        child: MonoArc::empty(),
+    };
+
+    let root = MonoArc::from(node());
+    let parent = root.get().unwrap();
+    let child = Arc::new(node());
+    assert!(child.parent.store_value(root.as_weak().unwrap()));
+    assert!(parent.child.store(child.clone()).is_ok());
+    let child_weak = Arc::downgrade(&child);
+    std::mem::drop(child);
+
+    // Back-references don't keep anything alive.
+    assert_eq!(Arc::strong_count(&parent), 2);
+    assert_eq!(Arc::weak_count(&parent), 1);
+    let back = parent.child.as_ref().unwrap().parent.as_ref().unwrap();
+    assert!(Arc::ptr_eq(&back.upgrade().unwrap(), &parent));
+
+    let parent_weak = Arc::downgrade(&parent);
+    std::mem::drop(parent);
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+    // Dropping the last strong reference drops both nodes, even with
+    // live `Weak`s (the child's back-reference and ours).
+    std::mem::drop(root);
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+    assert!(parent_weak.upgrade().is_none());
+    assert!(child_weak.upgrade().is_none());
+    assert_eq!(parent_weak.weak_count(), 0);
+}
+
 #[test]
 fn test_upgrade() {
     let mono: MonoArc<Vec<usize>> = Default::default();
@@ -331,6 +1446,98 @@ fn test_store_value() {
     assert_eq!(mono.as_ref().unwrap(), &[1]);
 }
 
+#[test]
+fn test_close() {
+    let mono: MonoArc<Vec<usize>> = Default::default();
+
+    assert!(!mono.is_closed());
+    assert!(mono.close());
+    assert!(mono.is_closed());
+    assert!(mono.is_none());
+    assert!(!mono.is_some());
+    assert!(!mono.close());
+
+    assert_eq!(mono.store(Arc::new(vec![1])), Err(Arc::new(vec![1])));
+    assert!(!mono.store_value(vec![2]));
+    assert!(mono.as_ref().is_none());
+    assert!(mono.get().is_none());
+    assert_eq!(mono.snapshot(), MonoSnapshot::default());
+    assert_eq!(format!("{:?}", &mono), "None");
+    assert_eq!(format!("{:p}", mono), "0x0");
+
+    let clone = mono.clone();
+    assert!(clone.is_closed());
+    assert!(clone.is_none());
+    std::mem::drop(clone);
+
+    assert_eq!(mono.into_inner(), None);
+}
+
+#[test]
+fn test_close_after_store() {
+    let mut mono: MonoArc<Vec<usize>> = Default::default();
+
+    assert!(mono.store_value(vec![1]));
+    assert!(!mono.close());
+    assert!(!mono.is_closed());
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+
+    // `&mut` lets us empty the `MonoArc`, and then close it.
+    assert_eq!(mono.take(), Some(Arc::new(vec![1])));
+    assert!(mono.close());
+
+    // And reopen it.
+    assert_eq!(mono.swap(Some(Arc::new(vec![2]))), None);
+    assert!(!mono.is_closed());
+    assert_eq!(mono.as_ref().unwrap(), &[2]);
+}
+
+#[test]
+fn test_store_detailed() {
+    let mono: MonoArc<Vec<usize>> = Default::default();
+
+    assert_eq!(mono.store_detailed(Arc::new(vec![1])), Ok(()));
+    assert_eq!(
+        mono.store_detailed(Arc::new(vec![2])),
+        Err((Arc::new(vec![2]), StoreReject::AlreadyPopulated))
+    );
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+
+    let closed: MonoArc<Vec<usize>> = Default::default();
+    assert!(closed.close());
+    assert_eq!(
+        closed.store_detailed(Arc::new(vec![3])),
+        Err((Arc::new(vec![3]), StoreReject::Closed))
+    );
+    assert!(closed.is_none());
+}
+
+#[cfg(feature = "debug-tracking")]
+#[test]
+fn test_storing_thread() {
+    let mono: MonoArc<usize> = Default::default();
+    assert_eq!(mono.storing_thread(), None);
+
+    let writer = std::thread::scope(|s| {
+        std::thread::Builder::new()
+            .name("writer".into())
+            .spawn_scoped(s, || {
+                assert!(mono.store_value(1));
+                std::thread::current().id()
+            })
+            .unwrap()
+            .join()
+            .unwrap()
+    });
+
+    assert_eq!(mono.storing_thread(), Some(writer));
+
+    // Losing stores don't overwrite the winner.
+    assert!(!mono.store_value(2));
+    assert_eq!(mono.storing_thread(), Some(writer));
+    assert_eq!(mono.clone().storing_thread(), Some(writer));
+}
+
 #[test]
 fn test_swap() {
     let mut mono: MonoArc<Vec<usize>> = Default::default();
@@ -350,6 +1557,309 @@ fn test_swap() {
     assert_eq!(mono.take(), None);
 }
 
+#[test]
+fn test_with_raw() {
+    let mono: MonoArc<usize> = Default::default();
+
+    assert!(mono.with_raw(|ptr| ptr.is_null()));
+
+    assert_eq!(mono.store(Arc::new(42)), Ok(()));
+    let expected = mono.as_ref().unwrap() as *const usize;
+    assert_eq!(mono.with_raw(|ptr| ptr), expected);
+    assert_eq!(mono.with_raw(|ptr| unsafe { *ptr }), 42);
+}
+
+#[test]
+fn test_from_box_moving() {
+    let mono = MonoArc::from_box_moving(MonoBox::<[u64; 64]>::empty());
+    assert!(mono.is_none());
+
+    let mut big = [0u64; 64];
+    for (i, x) in big.iter_mut().enumerate() {
+        *x = i as u64;
+    }
+
+    let boxed: MonoBox<[u64; 64]> = big.into();
+    let mono = MonoArc::from_box_moving(boxed);
+    assert_eq!(mono.as_ref().unwrap(), &big);
+    assert_eq!(mono.snapshot().strong, 1);
+}
+
+#[test]
+fn test_from_box() {
+    let mono: MonoArc<Vec<usize>> = Box::new(vec![1, 2]).into();
+    assert_eq!(mono.as_ref(), Some(&vec![1, 2]));
+    assert_eq!(mono.snapshot().strong, 1);
+
+    let mono = MonoArc::<[u64; 64]>::from(Box::new([3u64; 64]));
+    assert_eq!(mono.as_ref(), Some(&[3u64; 64]));
+}
+
+#[test]
+fn test_observe() {
+    let mono: MonoArc<Vec<usize>> = Default::default();
+    assert!(mono.observe().is_none());
+
+    assert!(mono.store_value(vec![1, 2]));
+    assert_eq!(mono.snapshot().strong, 1);
+
+    {
+        let observed = mono.observe().unwrap();
+        assert_eq!(mono.snapshot().strong, 2);
+
+        assert_eq!(observed.len(), 2);
+        assert_eq!(observed[0], 1);
+        assert_eq!(&*observed, &[1, 2]);
+        assert_eq!(format!("{:?}", observed), "[1, 2]");
+    }
+
+    assert_eq!(mono.snapshot().strong, 1);
+}
+
+#[test]
+fn test_observe_keeps_alive() {
+    use std::sync::atomic::AtomicUsize;
+
+    struct DropTracker<'a> {
+        value: usize,
+        counter: &'a AtomicUsize,
+    }
+
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = AtomicUsize::new(0);
+    let mono = MonoArc::from(DropTracker {
+        value: 1,
+        counter: &counter,
+    });
+    let mut other = mono.clone();
+
+    let observed = mono.observe().unwrap();
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            // Release the other handle's reference, and replace it.
+            std::mem::drop(other.take());
+            std::mem::drop(other.swap(Some(Arc::new(DropTracker {
+                value: 2,
+                counter: &counter,
+            }))));
+        });
+    });
+
+    assert_eq!(observed.value, 1);
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+    std::mem::drop(other);
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+    std::mem::drop(observed);
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+    std::mem::drop(mono);
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn test_replace() {
+    let mut mono: MonoArc<Vec<usize>> = Default::default();
+
+    let (old, new) = mono.replace(Arc::new(vec![1]));
+    assert_eq!(old, None);
+    assert_eq!(new, Arc::new(vec![1]));
+    assert_eq!(Arc::strong_count(&new), 2);
+
+    let (old, newer) = mono.replace(Arc::new(vec![2]));
+    assert!(Arc::ptr_eq(&old.unwrap(), &new));
+    assert_eq!(Arc::strong_count(&new), 1);
+    assert_eq!(Arc::strong_count(&newer), 2);
+    assert_eq!(mono.as_ref().unwrap(), &[2]);
+}
+
+#[test]
+fn test_swap_if_ptr() {
+    let mut mono: MonoArc<Vec<usize>> = Default::default();
+
+    let observed = Arc::new(vec![1]);
+    assert_eq!(
+        mono.swap_if_ptr(core::ptr::null(), Some(observed.clone())),
+        Ok(None)
+    );
+
+    // Mismatch: another allocation with the same value.
+    let other = Arc::new(vec![1]);
+    assert_eq!(
+        mono.swap_if_ptr(Arc::as_ptr(&other), Some(Arc::new(vec![2]))),
+        Err(Some(Arc::new(vec![2])))
+    );
+    assert_eq!(mono.swap_if_ptr(core::ptr::null(), None), Err(None));
+    assert!(Arc::ptr_eq(&mono.get().unwrap(), &observed));
+
+    // Match.
+    let old = mono
+        .swap_if_ptr(Arc::as_ptr(&observed), Some(Arc::new(vec![3])))
+        .unwrap()
+        .unwrap();
+    assert!(Arc::ptr_eq(&old, &observed));
+    assert_eq!(mono.as_ref().unwrap(), &[3]);
+}
+
+#[test]
+fn test_get_spin() {
+    let mono: MonoArc<usize> = Default::default();
+
+    assert_eq!(mono.get_spin(0), None);
+    assert_eq!(mono.get_spin(1000), None);
+
+    std::thread::scope(|s| {
+        let reader = s.spawn(|| mono.get_spin(u32::MAX));
+
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        assert!(mono.store_value(42));
+        assert_eq!(reader.join().unwrap(), Some(Arc::new(42)));
+    });
+
+    assert_eq!(mono.get_spin(0), Some(Arc::new(42)));
+}
+
+#[test]
+fn test_clone_into() {
+    let mut src: MonoArc<Vec<usize>> = vec![1].into();
+    let mut dst: MonoArc<Vec<usize>> = vec![0].into();
+    let old = dst.get().unwrap();
+
+    src.clone_into(&mut dst);
+    assert_eq!(dst.as_ref().unwrap(), &[1]);
+    assert_eq!(src.snapshot().strong, 2);
+    assert_eq!(Arc::strong_count(&old), 1);
+
+    // Refresh after the source changes.
+    std::mem::drop(src.swap(Some(Arc::new(vec![2]))));
+    src.clone_into(&mut dst);
+    assert_eq!(dst.as_ref().unwrap(), &[2]);
+    assert_eq!(src.snapshot().strong, 2);
+
+    let empty = MonoArc::<Vec<usize>>::empty();
+    empty.clone_into(&mut dst);
+    assert!(dst.is_none());
+    assert_eq!(src.snapshot().strong, 1);
+}
+
+#[test]
+fn test_guard() {
+    fn read(guard: Guard<'_, Vec<usize>>) -> Option<usize> {
+        guard.get().map(|value| value.len())
+    }
+
+    fn longest<'a>(x: Guard<'a, Vec<usize>>, y: Guard<'a, Vec<usize>>) -> Option<&'a Vec<usize>> {
+        match (x.get(), y.get()) {
+            (Some(x), Some(y)) if y.len() > x.len() => Some(y),
+            (x, y) => x.or(y),
+        }
+    }
+
+    let mono: MonoArc<Vec<usize>> = Default::default();
+    let other: MonoArc<Vec<usize>> = vec![1, 2].into();
+    let guard = mono.guard();
+
+    assert_eq!(read(guard), None);
+    assert_eq!(format!("{:?}", guard), "None");
+
+    assert!(mono.store_value(vec![1]));
+    // The same guard sees the new value.
+    assert_eq!(read(guard), Some(1));
+    assert_eq!(longest(guard, other.guard()), Some(&vec![1, 2]));
+
+    // References outlive the guard they came from.
+    let value = {
+        let guard = mono.guard();
+        guard.get().unwrap()
+    };
+    assert_eq!(value, &[1]);
+}
+
+#[test]
+fn test_project_field() {
+    struct Config {
+        name: String,
+        port: u16,
+    }
+
+    let empty = MonoArc::<Config>::empty();
+    assert!(empty.project_field(|config| &config.port).is_none());
+
+    let mono: MonoArc<Config> = Config {
+        name: "quinine".to_string(),
+        port: 8080,
+    }
+    .into();
+
+    let name = mono.project_field(|config| &config.name).unwrap();
+    let port = mono.project_field(|config| &config.port).unwrap();
+    let weak = Arc::downgrade(&mono.get().unwrap());
+    assert_eq!(weak.strong_count(), 3);
+
+    std::mem::drop(mono);
+    assert_eq!(&*name, "quinine");
+    assert_eq!(format!("{:?}", name), "\"quinine\"");
+    assert_eq!(*port, 8080);
+    assert_eq!(weak.strong_count(), 2);
+
+    let port_copy = port.clone();
+    assert_eq!(weak.strong_count(), 3);
+
+    std::mem::drop(name);
+    std::mem::drop(port);
+    assert_eq!(*port_copy, 8080);
+    assert_eq!(weak.strong_count(), 1);
+
+    std::mem::drop(port_copy);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn test_project_field_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mono: MonoArc<(u8, u16)> = (1, 2).into();
+    let weak = Arc::downgrade(&mono.get().unwrap());
+
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        mono.project_field(|_| -> &u16 { panic!("projection failed") })
+    }))
+    .is_err());
+    // The panic must not leak a strong reference.
+    assert_eq!(weak.strong_count(), 1);
+}
+
+#[test]
+fn test_freeze_all() {
+    let cells: Vec<MonoArc<usize>> = (0..4).map(|_| MonoArc::empty()).collect();
+
+    assert!(!all_populated(&cells));
+    assert_eq!(try_freeze_all(&cells), None);
+
+    for (i, cell) in cells.iter().enumerate().skip(1) {
+        assert!(cell.store_value(i));
+    }
+
+    assert!(!all_populated(&cells));
+    assert_eq!(try_freeze_all(&cells), None);
+
+    assert!(cells[0].store_value(0));
+    assert!(all_populated(&cells));
+
+    let frozen = try_freeze_all(&cells).unwrap();
+    assert_eq!(
+        frozen.iter().map(|value| **value).collect::<Vec<_>>(),
+        [0, 1, 2, 3]
+    );
+
+    assert!(all_populated::<usize>(&[]));
+    assert_eq!(try_freeze_all::<usize>(&[]), Some(vec![]));
+}
+
 #[test]
 fn test_fmt() {
     let mono = MonoArc::<()>::empty();
@@ -358,6 +1868,62 @@ fn test_fmt() {
     assert_eq!(format!("as a pointer: {:p}", mono), "as a pointer: 0x0");
 }
 
+#[test]
+fn test_eq_value() {
+    let mono: MonoArc<Vec<usize>> = vec![1].into();
+
+    assert_eq!(mono, vec![1]);
+    assert!(mono == vec![1]);
+    assert_ne!(mono, vec![2]);
+    assert!(mono != vec![2]);
+
+    let empty = MonoArc::<Vec<usize>>::empty();
+    assert_ne!(empty, vec![]);
+    assert!(empty != vec![1]);
+}
+
+#[test]
+fn test_eq() {
+    let mono: MonoArc<u32> = 1.into();
+
+    assert_eq!(mono, MonoArc::from(1));
+    assert_ne!(mono, MonoArc::from(2));
+    assert_ne!(mono, MonoArc::empty());
+
+    let closed = MonoArc::<u32>::empty();
+    assert!(closed.close());
+    assert_eq!(closed, MonoArc::empty());
+}
+
+#[test]
+fn test_hash() {
+    fn hash<T: core::hash::Hash>(value: &T) -> u64 {
+        use std::hash::Hasher;
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let empty = MonoArc::<String>::empty();
+    assert_eq!(hash(&empty), hash(&Option::<&String>::None));
+    assert_eq!(hash(&empty), hash(&empty.clone()));
+
+    let mono: MonoArc<String> = "foo".to_string().into();
+    let clone = mono.clone();
+    assert_eq!(hash(&mono), hash(&Some(&"foo".to_string())));
+    assert_eq!(hash(&mono), hash(&clone));
+
+    // Clones share the same pointee, so they land in the same bucket.
+    let mut set = std::collections::HashSet::new();
+    set.insert(hash(&mono));
+    assert!(set.contains(&hash(&clone)));
+
+    // Distinct allocations with equal values hash the same as well.
+    let other: MonoArc<String> = "foo".to_string().into();
+    assert_eq!(hash(&mono), hash(&other));
+}
+
 #[test]
 fn test_conversions() {
     let mono: MonoArc<_> = Option::<String>::None.into();
@@ -398,3 +1964,743 @@ fn test_conversions() {
     let mono: MonoArc<String> = boxed.into();
     assert_eq!(mono.as_deref(), Some("quux"));
 }
+
+#[test]
+fn test_snapshot() {
+    let mono = MonoArc::<Vec<usize>>::empty();
+
+    assert_eq!(mono.snapshot(), MonoSnapshot::default());
+    assert!(!mono.snapshot().populated);
+
+    let arc = Arc::new(vec![1]);
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+    assert_eq!(
+        mono.snapshot(),
+        MonoSnapshot {
+            populated: true,
+            strong: 2,
+            weak: 0,
+        }
+    );
+
+    let clone = mono.get();
+    let weak = Arc::downgrade(&arc);
+    assert_eq!(
+        mono.snapshot(),
+        MonoSnapshot {
+            populated: true,
+            strong: 3,
+            weak: 1,
+        }
+    );
+
+    std::mem::drop(clone);
+    std::mem::drop(arc);
+    assert_eq!(
+        mono.snapshot(),
+        MonoSnapshot {
+            populated: true,
+            strong: 1,
+            weak: 1,
+        }
+    );
+
+    std::mem::drop(weak);
+    assert_eq!(mono.snapshot().weak, 0);
+}
+
+#[test]
+fn test_map() {
+    let mono = MonoArc::<String>::empty();
+    assert_eq!(mono.map(|x| x.len()), None);
+
+    let arc = Arc::new("foo".to_string());
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+    assert_eq!(mono.map(|x| x.len()), Some(3));
+    assert_eq!(mono.map(|x| x.to_uppercase()), Some("FOO".to_string()));
+    // No extra reference: `map` borrows the value in place.
+    assert_eq!(Arc::strong_count(&arc), 2);
+
+    let closed = MonoArc::<String>::empty();
+    assert!(closed.close());
+    assert_eq!(closed.map(|x| x.len()), None);
+}
+
+#[test]
+fn test_store_filled() {
+    let arc = Arc::new(vec![1usize]);
+    let filled = MonoArc::empty()
+        .store_filled(arc.clone())
+        .expect("empty store should succeed");
+    assert_eq!(*filled, [1]);
+    assert_eq!(filled.len(), 1);
+    assert_eq!(format!("{:?}", filled), "[1]");
+    assert_eq!(Arc::strong_count(&arc), 2);
+
+    let mono: MonoArc<Vec<usize>> = filled.clone().into();
+    assert_eq!(mono.as_deref(), Some(&[1usize][..]));
+    assert!(Arc::ptr_eq(&filled.into_arc(), &arc));
+
+    // Populated containers are passed back untouched.
+    let (mono, rejected) = mono
+        .store_filled(Arc::new(vec![2]))
+        .expect_err("populated store should fail");
+    assert_eq!(*rejected, [2]);
+    assert!(Arc::ptr_eq(&mono.get().unwrap(), &arc));
+
+    // So are closed ones.
+    let closed = MonoArc::<Vec<usize>>::empty();
+    assert!(closed.close());
+    let (closed, rejected) = closed
+        .store_filled(Arc::new(vec![3]))
+        .expect_err("closed store should fail");
+    assert!(closed.is_closed());
+    assert_eq!(*rejected, [3]);
+}
+
+#[test]
+fn test_debug_state() {
+    let mono = MonoArc::<usize>::empty();
+    assert_eq!(mono.debug_state(), ContainerDebug::default());
+
+    let arc = Arc::new(1);
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+    assert_eq!(
+        mono.debug_state(),
+        ContainerDebug {
+            ptr_addr: Arc::as_ptr(&arc) as usize,
+            populated: true,
+        }
+    );
+
+    let closed = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    assert_eq!(
+        closed.debug_state(),
+        ContainerDebug {
+            ptr_addr: CLOSED,
+            populated: false,
+        }
+    );
+}
+
+#[test]
+fn test_as_weak() {
+    let mono = MonoArc::<usize>::empty();
+    assert!(mono.as_weak().is_none());
+
+    let arc = Arc::new(1);
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+
+    let weak = mono.as_weak().expect("populated");
+    assert_eq!(Arc::strong_count(&arc), 2);
+    assert_eq!(Arc::weak_count(&arc), 1);
+    assert_eq!(weak.upgrade().as_deref(), Some(&1));
+
+    // Dropping a `Weak` balances the count.
+    std::mem::drop(mono.as_weak());
+    assert_eq!(Arc::weak_count(&arc), 1);
+
+    std::mem::drop(arc);
+    assert!(weak.upgrade().is_some());
+    std::mem::drop(mono);
+    assert!(weak.upgrade().is_none());
+    assert_eq!(weak.strong_count(), 0);
+}
+
+#[test]
+fn test_store_uninit() {
+    type Big = [u64; 4096];
+
+    let mut uninit = Arc::<Big>::new_uninit();
+    let slot = Arc::get_mut(&mut uninit).expect("fresh Arc is unique");
+    // Fill the value in place, one element at a time.
+    let base = slot.as_mut_ptr() as *mut u64;
+    for i in 0..4096 {
+        unsafe { base.add(i).write(i as u64) };
+    }
+    let arc = unsafe { uninit.assume_init() };
+    let addr = Arc::as_ptr(&arc);
+
+    let mono = MonoArc::<Big>::empty();
+    assert_eq!(mono.store(arc).map_err(|_| ()), Ok(()));
+    // Same allocation, no copy.
+    assert_eq!(mono.as_ref().map(|x| x as *const Big), Some(addr));
+    assert_eq!(mono.as_ref().map(|x| x[4095]), Some(4095));
+}
+
+#[test]
+fn test_into_arc() {
+    assert!(MonoArc::<usize>::empty().into_arc().is_none());
+
+    let closed = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    assert!(closed.into_arc().is_none());
+
+    let arc = Arc::new(1);
+    let mono = MonoArc::from(arc.clone());
+    assert_eq!(Arc::strong_count(&arc), 2);
+
+    let extracted = mono.into_arc().expect("populated");
+    assert!(Arc::ptr_eq(&extracted, &arc));
+    assert_eq!(Arc::strong_count(&arc), 2);
+
+    std::mem::drop(extracted);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
+
+#[test]
+fn test_clone_relaxed() {
+    let mono = MonoArc::<usize>::empty();
+    let clone = unsafe { mono.clone_relaxed() };
+    assert!(clone.is_none());
+
+    let arc = Arc::new(1);
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+
+    // We stored the value on this thread, so a `Relaxed` load is
+    // enough.
+    let clone = unsafe { mono.clone_relaxed() };
+    assert_eq!(clone.as_ref(), Some(&1));
+    assert_eq!(Arc::strong_count(&arc), 3);
+    std::mem::drop(clone);
+    assert_eq!(Arc::strong_count(&arc), 2);
+
+    let closed = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    assert!(unsafe { closed.clone_relaxed() }.is_closed());
+}
+
+#[test]
+fn test_store_and_downgrade() {
+    let mono = MonoArc::<usize>::empty();
+
+    let weak = mono
+        .store_and_downgrade(Arc::new(1))
+        .expect("empty store should succeed");
+    assert_eq!(weak.strong_count(), 1);
+    assert_eq!(weak.upgrade().as_deref(), Some(&1));
+
+    let rejected = mono
+        .store_and_downgrade(Arc::new(2))
+        .expect_err("populated store should fail");
+    assert_eq!(*rejected, 2);
+    assert_eq!(Arc::weak_count(&rejected), 0);
+    assert_eq!(mono.snapshot().weak, 1);
+
+    std::mem::drop(mono);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn test_store_idempotent() {
+    let mono = MonoArc::<String>::empty();
+
+    assert_eq!(mono.store_idempotent(Arc::new("foo".to_string())), Ok(()));
+    let first = mono.get().unwrap();
+
+    // Equal values are accepted, but don't replace the original.
+    assert_eq!(mono.store_idempotent(Arc::new("foo".to_string())), Ok(()));
+    assert!(Arc::ptr_eq(&mono.get().unwrap(), &first));
+
+    assert_eq!(
+        mono.store_idempotent(Arc::new("bar".to_string())),
+        Err(Arc::new("bar".to_string()))
+    );
+
+    let closed = MonoArc::<String>::empty();
+    assert!(closed.close());
+    assert!(closed
+        .store_idempotent(Arc::new("foo".to_string()))
+        .is_err());
+
+    // Racing threads that all store equal values all succeed.
+    let shared = MonoArc::<String>::empty();
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                assert_eq!(shared.store_idempotent(Arc::new("baz".to_string())), Ok(()));
+            });
+        }
+    });
+    assert_eq!(shared.as_deref(), Some("baz"));
+}
+
+#[test]
+fn test_get_mut_or_clone() {
+    let mut mono = MonoArc::<Vec<usize>>::empty();
+    assert_eq!(mono.get_mut_or_clone(), None);
+
+    let mut closed = MonoArc::<Vec<usize>>::empty();
+    assert!(closed.close());
+    assert_eq!(closed.get_mut_or_clone(), None);
+    assert!(closed.is_closed());
+
+    // Unique: mutate in place.
+    assert!(mono.store_value(vec![1]));
+    let addr = mono.as_ref().unwrap() as *const Vec<usize>;
+    mono.get_mut_or_clone().unwrap().push(2);
+    assert_eq!(mono.as_ref().unwrap() as *const Vec<usize>, addr);
+    assert_eq!(mono.as_deref(), Some(&[1usize, 2][..]));
+
+    // Shared: clone into a fresh `Arc`.
+    let shared = mono.get().unwrap();
+    mono.get_mut_or_clone().unwrap().push(3);
+    assert_eq!(mono.as_deref(), Some(&[1usize, 2, 3][..]));
+    assert_eq!(*shared, [1, 2]);
+    assert_eq!(Arc::strong_count(&shared), 1);
+    assert_eq!(mono.snapshot().strong, 1);
+}
+
+#[test]
+fn test_panic_safety() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[derive(Debug, PartialEq)]
+    struct PanicClone(usize);
+
+    impl Clone for PanicClone {
+        fn clone(&self) -> Self {
+            panic!("clone failed");
+        }
+    }
+
+    let mut mono = MonoArc::<PanicClone>::empty();
+    assert!(mono.store_value(PanicClone(1)));
+    let shared = mono.get().unwrap();
+
+    // Cloning the shared value panics: the container must still hold
+    // the original value, with balanced counts.
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        mono.get_mut_or_clone();
+    }))
+    .is_err());
+    assert!(Arc::ptr_eq(&mono.get().unwrap(), &shared));
+    assert_eq!(Arc::strong_count(&shared), 2);
+
+    // Panicking read-side closures leave the container alone too.
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        mono.map(|_| panic!("map failed"));
+    }))
+    .is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        mono.with_raw(|_| panic!("with_raw failed"));
+    }))
+    .is_err());
+    assert_eq!(mono.as_ref(), Some(&PanicClone(1)));
+    assert_eq!(Arc::strong_count(&shared), 2);
+
+    // Once unique, we don't clone anymore.
+    std::mem::drop(shared);
+    mono.get_mut_or_clone().unwrap().0 = 2;
+    assert_eq!(mono.as_ref(), Some(&PanicClone(2)));
+}
+
+#[test]
+fn test_get_pinned() {
+    struct SelfAware {
+        value: usize,
+        _pin: core::marker::PhantomPinned,
+    }
+
+    impl SelfAware {
+        fn get(self: core::pin::Pin<&Self>) -> usize {
+            self.value
+        }
+    }
+
+    let mono = MonoArc::<SelfAware>::empty();
+    assert!(unsafe { mono.get_pinned() }.is_none());
+
+    assert!(mono.store_value(SelfAware {
+        value: 1,
+        _pin: core::marker::PhantomPinned,
+    }));
+    let pinned = unsafe { mono.get_pinned() }.expect("populated");
+    assert_eq!(pinned.as_ref().get(), 1);
+    assert_eq!(mono.snapshot().strong, 2);
+    assert!(std::ptr::eq(&*pinned, mono.as_ref().unwrap()));
+}
+
+#[test]
+fn test_is_unique() {
+    let mono = MonoArc::<usize>::empty();
+    assert_eq!(mono.is_unique(), None);
+
+    assert!(mono.store_value(1));
+    assert_eq!(mono.is_unique(), Some(true));
+
+    let strong = mono.get().unwrap();
+    assert_eq!(mono.is_unique(), Some(false));
+    std::mem::drop(strong);
+    assert_eq!(mono.is_unique(), Some(true));
+
+    let weak = mono.as_weak().unwrap();
+    assert_eq!(mono.is_unique(), Some(false));
+    std::mem::drop(weak);
+    assert_eq!(mono.is_unique(), Some(true));
+}
+
+#[test]
+fn test_store_and_mirror() {
+    const THREADS: usize = 4;
+
+    let primary = MonoArc::<usize>::empty();
+    let mirror = MonoArc::<usize>::empty();
+    let barrier = std::sync::Barrier::new(THREADS);
+
+    let results: Vec<Arc<usize>> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..THREADS)
+            .map(|i| {
+                let (primary, mirror, barrier) = (&primary, &mirror, &barrier);
+                s.spawn(move || {
+                    barrier.wait();
+                    primary.store_and_mirror(Arc::new(i), mirror)
+                })
+            })
+            .collect();
+
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    let winner = primary.get().unwrap();
+    assert!(Arc::ptr_eq(&mirror.get().unwrap(), &winner));
+    assert!(results.iter().all(|arc| Arc::ptr_eq(arc, &winner)));
+
+    // Closed primaries leave `value` canonical.
+    let closed = MonoArc::<usize>::empty();
+    let mirror = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    let value = Arc::new(1);
+    assert!(Arc::ptr_eq(
+        &closed.store_and_mirror(value.clone(), &mirror),
+        &value
+    ));
+    assert!(Arc::ptr_eq(&mirror.get().unwrap(), &value));
+}
+
+#[test]
+fn test_fill_from() {
+    let cells: Vec<MonoArc<usize>> = (0..6).map(|_| MonoArc::empty()).collect();
+    assert!(cells[1].store_value(10));
+    assert!(cells[2].store_value(20));
+    assert!(cells[4].close());
+
+    fill_from(&cells, (0..).map(Arc::new));
+
+    let values: Vec<Option<usize>> = cells.iter().map(|cell| cell.as_ref().copied()).collect();
+    assert_eq!(
+        values,
+        [Some(0), Some(10), Some(20), Some(1), None, Some(2)]
+    );
+
+    // Running out of values leaves the remaining cells empty.
+    let cells: Vec<MonoArc<usize>> = (0..3).map(|_| MonoArc::empty()).collect();
+    fill_from(&cells, [Arc::new(1)]);
+    assert!(cells[0].is_some());
+    assert!(!all_populated(&cells[1..]));
+}
+
+#[test]
+fn test_store_weak_upgraded() {
+    let mono = MonoArc::<usize>::empty();
+
+    let dead = Arc::downgrade(&Arc::new(0));
+    assert_eq!(mono.store_weak_upgraded(dead), Err(()));
+    assert!(mono.is_none());
+
+    let arc = Arc::new(1);
+    assert_eq!(mono.store_weak_upgraded(Arc::downgrade(&arc)), Ok(()));
+    assert!(Arc::ptr_eq(&mono.get().unwrap(), &arc));
+    assert_eq!(Arc::strong_count(&arc), 2);
+
+    let other = Arc::new(2);
+    assert_eq!(mono.store_weak_upgraded(Arc::downgrade(&other)), Err(()));
+    assert_eq!(Arc::strong_count(&other), 1);
+    assert_eq!(mono.as_ref(), Some(&1));
+}
+
+#[test]
+fn test_as_ref_and_ptr() {
+    let mono = MonoArc::<usize>::empty();
+    assert_eq!(mono.as_ref_and_ptr(), None);
+
+    let closed = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    assert_eq!(closed.as_ref_and_ptr(), None);
+
+    let arc = Arc::new(1);
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+    let (value, ptr) = mono.as_ref_and_ptr().expect("populated");
+    assert_eq!(*value, 1);
+    assert!(core::ptr::eq(value, ptr));
+    assert_eq!(ptr, Arc::as_ptr(&arc));
+}
+
+#[test]
+fn test_with() {
+    let mono = MonoArc::<usize>::empty();
+    assert_eq!(mono.with(|value| value.copied()), None);
+
+    let arc = Arc::new(1);
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+    assert_eq!(
+        mono.with(|value| {
+            // No reference count bump while `f` runs, either.
+            assert_eq!(Arc::strong_count(&arc), 2);
+            value.copied()
+        }),
+        Some(1)
+    );
+    assert_eq!(Arc::strong_count(&arc), 2);
+}
+
+#[test]
+fn test_clone_if_some() {
+    let mono = MonoArc::<usize>::empty();
+    assert!(mono.clone_if_some().is_none());
+
+    let closed = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    assert!(closed.clone_if_some().is_none());
+
+    let arc = Arc::new(1);
+    assert_eq!(mono.store(arc.clone()), Ok(()));
+    let clone = mono.clone_if_some().expect("populated");
+    assert_eq!(Arc::strong_count(&arc), 3);
+    assert!(Arc::ptr_eq(&clone.get().unwrap(), &arc));
+    std::mem::drop(clone);
+    assert_eq!(Arc::strong_count(&arc), 2);
+}
+
+#[cfg(feature = "debug-monotonic")]
+#[test]
+fn test_strict() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut mono = MonoArc::<usize>::new_strict();
+    // Nothing to discard yet.
+    assert_eq!(mono.take(), None);
+    assert_eq!(mono.swap(Some(Arc::new(1))), None);
+
+    if !cfg!(debug_assertions) {
+        // The check is compiled out of release builds.
+        assert_eq!(mono.take().as_deref(), Some(&1));
+        return;
+    }
+
+    let message =
+        catch_unwind(AssertUnwindSafe(|| mono.take())).expect_err("strict take should panic");
+    assert_eq!(
+        message.downcast_ref::<&str>(),
+        Some(&"strict MonoArc would discard its value")
+    );
+    assert_eq!(mono.as_ref(), Some(&1));
+
+    let mut clone = mono.clone();
+    assert!(catch_unwind(AssertUnwindSafe(|| clone.swap(None))).is_err());
+
+    // Consuming strict containers is fine.
+    assert_eq!(mono.clone().into_inner().as_deref(), Some(&1));
+    std::mem::drop(mono);
+
+    // Regular containers are unaffected.
+    let mut mono = MonoArc::from(1);
+    assert_eq!(mono.take().as_deref(), Some(&1));
+}
+
+#[test]
+fn test_drop_all() {
+    use std::sync::atomic::AtomicUsize;
+
+    struct DropTracker<'a> {
+        counter: &'a AtomicUsize,
+    }
+
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = AtomicUsize::new(0);
+    let shared = Arc::new(DropTracker { counter: &counter });
+    let mut cells: Vec<MonoArc<DropTracker>> = (0..100)
+        .map(|i| match i % 3 {
+            0 => MonoArc::empty(),
+            1 => MonoArc::from(DropTracker { counter: &counter }),
+            _ => MonoArc::from(shared.clone()),
+        })
+        .collect();
+    let closed = MonoArc::empty();
+    assert!(closed.close());
+    cells.push(closed);
+
+    drop_all(cells);
+    // Every uniquely owned value was dropped exactly once, and the
+    // shared value only lost the containers' references.
+    assert_eq!(counter.load(Ordering::Relaxed), 33);
+    assert_eq!(Arc::strong_count(&shared), 1);
+    std::mem::drop(shared);
+    assert_eq!(counter.load(Ordering::Relaxed), 34);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_lock() {
+    use std::sync::Mutex;
+
+    let mono: MonoArc<Mutex<Vec<usize>>> = MonoArc::empty();
+    assert!(mono.lock().is_none());
+
+    assert!(mono.store_value(Mutex::new(vec![1])));
+    mono.lock().expect("populated").push(2);
+    assert_eq!(*mono.lock().unwrap(), vec![1, 2]);
+
+    let closed: MonoArc<Mutex<usize>> = MonoArc::empty();
+    assert!(closed.close());
+    assert!(closed.lock().is_none());
+}
+
+#[test]
+fn test_store_first() {
+    use std::cell::Cell;
+
+    let consumed = Cell::new(0);
+    let lazy = || (1..).inspect(|_: &usize| consumed.set(consumed.get() + 1));
+
+    let mono = MonoArc::<usize>::empty();
+    assert!(!mono.store_first(None));
+    assert!(mono.store_first(lazy()));
+    assert_eq!(mono.as_ref(), Some(&1));
+    assert_eq!(consumed.get(), 1);
+
+    assert!(!mono.store_first(lazy()));
+    let closed = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    assert!(!closed.store_first(lazy()));
+    assert_eq!(consumed.get(), 1);
+}
+
+#[test]
+fn test_reinterpret() {
+    #[repr(transparent)]
+    #[derive(Debug, PartialEq)]
+    struct Meters(u32);
+
+    let mono: MonoArc<u32> = MonoArc::from(42);
+    let meters: MonoArc<Meters> = unsafe { mono.reinterpret() };
+    assert_eq!(meters.as_ref(), Some(&Meters(42)));
+    assert_eq!(meters.snapshot().strong, 1);
+
+    let back: MonoArc<u32> = unsafe { meters.reinterpret() };
+    assert_eq!(back.into_arc(), Some(Arc::new(42)));
+
+    let empty: MonoArc<Meters> = unsafe { MonoArc::<u32>::empty().reinterpret() };
+    assert!(empty.is_none() && !empty.is_closed());
+
+    let closed = MonoArc::<u32>::empty();
+    assert!(closed.close());
+    let closed: MonoArc<Meters> = unsafe { closed.reinterpret() };
+    assert!(closed.is_closed());
+    assert!(!closed.store_value(Meters(1)));
+}
+
+#[test]
+fn test_store_scoped() {
+    let mut mono: MonoArc<Vec<usize>> = Default::default();
+
+    {
+        let scoped = mono.store_scoped(Arc::new(vec![1]));
+        assert_eq!(scoped.as_ref().unwrap(), &[1]);
+    }
+    assert!(mono.is_none());
+
+    let scoped = mono.store_scoped(Arc::new(vec![2]));
+    assert_eq!(scoped.commit(), None);
+    assert_eq!(mono.as_ref().unwrap(), &[2]);
+
+    {
+        let shared = Arc::new(vec![3]);
+        let scoped = mono.store_scoped(shared.clone());
+        assert_eq!(scoped.get(), Some(shared.clone()));
+        std::mem::drop(scoped);
+        // The rollback released the container's reference.
+        assert_eq!(Arc::strong_count(&shared), 1);
+    }
+    assert_eq!(mono.as_ref().unwrap(), &[2]);
+
+    let scoped = mono.store_scoped(Arc::new(vec![4]));
+    assert_eq!(scoped.commit(), Some(Arc::new(vec![2])));
+    assert_eq!(mono.as_ref().unwrap(), &[4]);
+}
+
+#[test]
+fn test_shares_with() {
+    let empty = MonoArc::<usize>::empty();
+    assert!(!empty.shares_with(&empty));
+    assert!(!empty.shares_with(&MonoArc::empty()));
+
+    let closed = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    let other_closed = MonoArc::<usize>::empty();
+    assert!(other_closed.close());
+    assert!(!closed.shares_with(&other_closed));
+
+    let arc = Arc::new(1);
+    let left = MonoArc::<usize>::from(arc.clone());
+    let right = MonoArc::<usize>::from(arc);
+    assert!(left.shares_with(&right));
+    assert!(right.shares_with(&left));
+    assert!(left.shares_with(&left.clone()));
+    assert!(!left.shares_with(&empty));
+    assert!(!empty.shares_with(&left));
+
+    // Equal values in distinct allocations don't count.
+    let distinct = MonoArc::from(1);
+    assert_eq!(left, distinct);
+    assert!(!left.shares_with(&distinct));
+}
+
+#[test]
+fn test_get_or_init_arc() {
+    use std::sync::Barrier;
+
+    const THREADS: usize = 4;
+
+    let mono = MonoArc::<usize>::empty();
+    let barrier = Barrier::new(THREADS);
+
+    // Make every thread build its `Arc` before anyone stores.
+    let arcs: Vec<Arc<usize>> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..THREADS)
+            .map(|id| {
+                let (mono, barrier) = (&mono, &barrier);
+                s.spawn(move || {
+                    mono.get_or_init_arc(|| {
+                        barrier.wait();
+                        Arc::new(id)
+                    })
+                })
+            })
+            .collect();
+
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    let winner = mono.get().expect("populated");
+    assert!(arcs.iter().all(|arc| Arc::ptr_eq(arc, &winner)));
+    // Losers' `Arc`s were dropped: only the container, `arcs`, and
+    // `winner` hold references.
+    assert_eq!(Arc::strong_count(&winner), THREADS + 2);
+
+    assert!(Arc::ptr_eq(
+        &mono.get_or_init_arc(|| panic!("should not be called")),
+        &winner
+    ));
+
+    let closed = MonoArc::<usize>::empty();
+    assert!(closed.close());
+    assert_eq!(closed.get_or_init_arc(|| Arc::new(1)), Arc::new(1));
+    assert!(closed.is_closed());
+}
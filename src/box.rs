@@ -1,8 +1,11 @@
 extern crate alloc;
 
 use alloc::boxed::Box;
-use core::sync::atomic::AtomicPtr;
-use core::sync::atomic::Ordering;
+
+use crate::ordering as Ordering;
+use crate::sync::AtomicPtr;
+use crate::Guard;
+use crate::MonoArc;
 
 /// A [`MonoBox<T>`] is an atomic, lock-free, write-once
 /// [`Option<Box<T>>`].  Write-once means that a [`MonoBox`] can only
@@ -15,10 +18,73 @@ use core::sync::atomic::Ordering;
 /// [`Option<Box<T>>`].  This non-monotonic operation is safe because
 /// the mutable references guarantees no other thread can observe the
 /// transition.
+///
+/// A [`MonoBox<T>`] is `#[repr(transparent)]` over an
+/// [`AtomicPtr<T>`](core::sync::atomic::AtomicPtr), so unsafe code may
+/// cast a `&MonoBox<T>` to a `&AtomicPtr<T>`.  The pointer is null
+/// when the [`MonoBox`] is empty, and the result of
+/// [`Box::into_raw`] otherwise.  Code that goes through such a cast
+/// must uphold the [`MonoBox`]'s invariants: only ever replace null
+/// with a [`Box::into_raw`] pointer (e.g., with a `compare_exchange`
+/// that has at least `Release` ordering on success), and never
+/// modify a non-null pointer.
+#[repr(transparent)]
 pub struct MonoBox<T> {
     ptr_or_null: AtomicPtr<T>,
 }
 
+/// A [`ContainerDebug`] records the raw state of a [`MonoBox`] or
+/// [`MonoArc`], as returned by [`MonoBox::debug_state`] and
+/// [`MonoArc::debug_state`].  Unlike [`Debug`](core::fmt::Debug), it
+/// doesn't need `T: Debug`, and is cheap to log.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ContainerDebug {
+    /// The raw pointer stored in the container, as an address.
+    pub ptr_addr: usize,
+    /// Whether the container held a value.
+    pub populated: bool,
+}
+
+/// A [`ScopedStore`] guard reverts a [`MonoBox`] to its previous
+/// state when dropped, unless [`ScopedStore::commit`]ted first.  See
+/// [`MonoBox::scoped_store`].
+pub struct ScopedStore<'a, T> {
+    mono: &'a mut MonoBox<T>,
+    // The value to restore on drop, if we haven't committed.
+    previous: Option<Option<Box<T>>>,
+}
+
+impl<T> ScopedStore<'_, T> {
+    /// Keeps the scoped value in the [`MonoBox`], and returns the
+    /// value it replaced, if any.
+    pub fn commit(mut self) -> Option<Box<T>> {
+        self.previous.take().flatten()
+    }
+}
+
+impl<T> core::ops::Deref for ScopedStore<'_, T> {
+    type Target = MonoBox<T>;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn deref(&self) -> &MonoBox<T> {
+        self.mono
+    }
+}
+
+impl<T> Drop for ScopedStore<'_, T> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take() {
+            core::mem::drop(self.mono.swap(previous));
+        }
+    }
+}
+
+/// A [`ChildRef`] is the [`Guard`] for a [`MonoBox`], returned by
+/// [`MonoBox::child`].  Since its references live as long as the
+/// [`MonoBox`] itself, it makes it easy to walk recursive structures
+/// linked with [`MonoBox`]es, one handle at a time.
+pub type ChildRef<'a, T> = Guard<'a, T, MonoBox<T>>;
+
 impl<T> MonoBox<T> {
     /// Returns a fresh [`MonoBox`] that holds `inner`.
     ///
@@ -56,15 +122,15 @@ impl<T> MonoBox<T> {
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn swap(&mut self, value: Option<Box<T>>) -> Option<Box<T>> {
         let new = value.map(Box::into_raw).unwrap_or_else(core::ptr::null_mut);
-        // We should be able to use `Relaxed` loads and store here,
-        // and rely on the ordering that guarantees `self` is `&mut`.
-        // However, it's more obviously safe when every load and store
-        // can be matched as acquires and releases.
-        let old = self.ptr_or_null.load(Ordering::Acquire);
+        // `Relaxed` is enough: whatever handed us a `&mut` reference
+        // (a join, a channel, a lock, ...) already made every earlier
+        // store happen-before this call, and will make our store
+        // happen-before any later access from another thread.
+        let old = self.ptr_or_null.load(Ordering::Relaxed);
 
         // We don't need or want an atomic swap here: `&mut`
         // guarantees exclusive ownership.
-        self.ptr_or_null.store(new, Ordering::Release);
+        self.ptr_or_null.store(new, Ordering::Relaxed);
         if old.is_null() {
             None
         } else {
@@ -72,6 +138,22 @@ impl<T> MonoBox<T> {
         }
     }
 
+    /// Replaces the contents of this [`MonoBox`] with `value`, until
+    /// the returned guard is dropped: unless the guard is
+    /// [`ScopedStore::commit`]ted, dropping it restores the
+    /// [`MonoBox`]'s previous contents (and drops `value`).
+    ///
+    /// Like [`MonoBox::swap`], this requires exclusive ownership, so
+    /// no other thread can observe the transient state.
+    pub fn scoped_store(&mut self, value: Box<T>) -> ScopedStore<'_, T> {
+        let previous = self.swap(Some(value));
+
+        ScopedStore {
+            mono: self,
+            previous: Some(previous),
+        }
+    }
+
     /// Attempts to store `value` in this [`MonoBox`].  The operation
     /// succeeds iff it upgrades the [`MonoBox`] from [`None`] to
     /// [`Some`].
@@ -92,12 +174,161 @@ impl<T> MonoBox<T> {
         }
     }
 
+    /// Attempts to store `value` in this [`MonoBox`], like
+    /// [`MonoBox::store`], but first checks for an existing value
+    /// with a `Relaxed` load, and fails immediately (without any
+    /// read-modify-write) when the [`MonoBox`] is already populated.
+    ///
+    /// The hint is racy, but conservative: a non-null pointer can
+    /// never revert to null while we hold a shared reference, so the
+    /// early failure is always correct.  When the hint sees a null
+    /// pointer, we fall back to [`MonoBox::store`]'s
+    /// `compare_exchange`.  Prefer this method when the [`MonoBox`]
+    /// is usually populated by the time stores happen.
+    pub fn store_hinted(&self, value: Box<T>) -> Result<(), Box<T>> {
+        if self.is_some() {
+            return Err(value);
+        }
+
+        self.store(value)
+    }
+
+    /// Attempts to store `value` in this [`MonoBox`], like
+    /// [`MonoBox::store`], and also returns the number of
+    /// `compare_exchange` attempts the store took.
+    ///
+    /// [`MonoBox::store`] uses a single strong `compare_exchange`, so
+    /// the count is currently always 1; it's there to surface
+    /// contention should the implementation switch to a retry loop.
+    pub fn store_counted(&self, value: Box<T>) -> (Result<(), Box<T>>, u32) {
+        (self.store(value), 1)
+    }
+
+    /// Unconditionally replaces the pointer stored in this
+    /// [`MonoBox`] with `ptr`, and returns the previous pointer
+    /// ([`None`] if the [`MonoBox`] was empty).  The swap is a single
+    /// [`AtomicPtr::swap`](core::sync::atomic::AtomicPtr::swap) with
+    /// `AcqRel` ordering.
+    ///
+    /// This is an escape hatch from write-once semantics, e.g., to
+    /// build the head of a Treiber stack out of a [`MonoBox`].  The
+    /// new pointer is never null, so a populated [`MonoBox`] stays
+    /// populated.
+    ///
+    /// # Safety
+    ///
+    /// The caller takes on all the invariants the [`MonoBox`] usually
+    /// upholds:
+    ///
+    ///  - `ptr` must come from [`Box::into_raw`] or [`Box::leak`],
+    ///    and its ownership transfers to the [`MonoBox`];
+    ///  - unless the [`MonoBox`] is empty, or the caller knows that no
+    ///    `&T` borrows of its current value are outstanding, other
+    ///    threads may still hold references to the old value
+    ///    (obtained with [`MonoBox::as_ref`]): the caller must then
+    ///    keep the old value alive, and must not mutate it, for as long
+    ///    as the [`MonoBox`] itself lives (e.g., by linking it from the
+    ///    new value);
+    ///  - in any case, ownership of the returned pointer transfers to
+    ///    the caller, who must eventually free it, since dropping the
+    ///    [`MonoBox`] only frees its current value.
+    pub unsafe fn store_returning_old(
+        &self,
+        ptr: core::ptr::NonNull<T>,
+    ) -> Option<core::ptr::NonNull<T>> {
+        core::ptr::NonNull::new(self.ptr_or_null.swap(ptr.as_ptr(), Ordering::AcqRel))
+    }
+
+    /// Stores `value` in this [`MonoBox`] with a plain `Relaxed`
+    /// store, without the `compare_exchange` in [`MonoBox::store`].
+    ///
+    /// This trims the setup cost of filling large tables of
+    /// [`MonoBox`]es on one thread before publishing them to other
+    /// threads.  The publication itself (e.g., spawning a thread,
+    /// sending an [`Arc`](alloc::sync::Arc) over a channel, or a
+    /// `Release` fence) must provide the happens-before edge.
+    ///
+    /// # Safety
+    ///
+    /// The [`MonoBox`] must be empty, and must not be shared yet: no
+    /// other thread may access it concurrently with this call.
+    pub unsafe fn set_unsynchronized(&self, value: Box<T>) {
+        debug_assert!(self.is_none());
+        self.ptr_or_null
+            .store(Box::into_raw(value), Ordering::Relaxed);
+    }
+
     /// Attempts to store `value` in this [`MonoBox`].
     ///
     /// Returns true on success and false if there was already some
     /// value in the [`MonoBox`].
     pub fn store_value(&self, value: T) -> bool {
-        self.store(Box::new(value)).is_ok()
+        self.store_discarding(Box::new(value))
+    }
+
+    /// Attempts to store the first item from `iter` in this
+    /// [`MonoBox`], and ignores the rest of the iterator.
+    ///
+    /// Returns true if that item populated the [`MonoBox`], and false
+    /// if `iter` is empty or the [`MonoBox`] was already populated.
+    /// The iterator is advanced at most once, and not at all if the
+    /// [`MonoBox`] is already populated when we start.
+    pub fn store_first(&self, iter: impl IntoIterator<Item = T>) -> bool {
+        if self.is_some() {
+            return false;
+        }
+
+        match iter.into_iter().next() {
+            Some(value) => self.store_value(value),
+            None => false,
+        }
+    }
+
+    /// Attempts to store `value` in this [`MonoBox`], like
+    /// [`MonoBox::store`], but drops `value` on failure instead of
+    /// passing it back.
+    ///
+    /// Returns true on success and false if there was already some
+    /// value in the [`MonoBox`], exactly like `store(value).is_ok()`.
+    pub fn store_discarding(&self, value: Box<T>) -> bool {
+        let ptr = Box::into_raw(value);
+
+        match self.ptr_or_null.compare_exchange(
+            core::ptr::null_mut(),
+            ptr,
+            Ordering::Release,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => true,
+            Err(_) => {
+                core::mem::drop(unsafe { Box::from_raw(ptr) });
+                false
+            }
+        }
+    }
+
+    /// Boxes `value`, lets `init` finish initialising it while it's
+    /// still private to the current thread, and then attempts to
+    /// store the result in this [`MonoBox`].  Other threads can only
+    /// ever observe the value after `init` has returned.
+    ///
+    /// Returns [`Ok`] when the store succeeds, and passes back the
+    /// (initialised) value as [`Err`] otherwise.
+    pub fn build_and_store(&self, init: impl FnOnce(&mut T), value: T) -> Result<(), T> {
+        let mut value = Box::new(value);
+
+        init(&mut value);
+        self.store(value).map_err(|value| *value)
+    }
+
+    /// Attempts to store `value`, converted to a [`Box<T>`], in this
+    /// [`MonoBox`].  This accepts a `T` as well as an existing
+    /// [`Box<T>`], and is otherwise identical to [`MonoBox::store`].
+    ///
+    /// Returns [`Ok`] when the store succeeds, and passes back the
+    /// converted `value` as [`Err`] otherwise.
+    pub fn store_into(&self, value: impl Into<Box<T>>) -> Result<(), Box<T>> {
+        self.store(value.into())
     }
 
     /// Gets the value stored in this [`MonoBox`], if any.
@@ -107,6 +338,42 @@ impl<T> MonoBox<T> {
         unsafe { ptr.as_ref() }
     }
 
+    /// Gets the value stored in this [`MonoBox`], if any.
+    ///
+    /// This is an alias of [`MonoBox::as_ref`], so that code written
+    /// against [`MonoArc::get`] carries over: a [`Box`] can't be
+    /// cloned out like an [`Arc`](alloc::sync::Arc), so we return a
+    /// reference instead.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn get(&self) -> Option<&T> {
+        self.as_ref()
+    }
+
+    /// Gets a pointer to the value stored in this [`MonoBox`], if
+    /// any, without creating a reference to that value.
+    ///
+    /// The pointee is valid for as long as the [`MonoBox`] isn't
+    /// mutated via `&mut` or dropped, and may only be used for shared
+    /// (read-only) accesses, like [`MonoBox::as_ref`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn as_non_null(&self) -> Option<core::ptr::NonNull<T>> {
+        core::ptr::NonNull::new(self.ptr_or_null.load(Ordering::Acquire))
+    }
+
+    /// Gets the value stored in this [`MonoBox`], if any, along with
+    /// its address, from a single load.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn as_ref_and_ptr(&self) -> Option<(&T, *const T)> {
+        let ptr: *const T = self.ptr_or_null.load(Ordering::Acquire);
+        unsafe { ptr.as_ref() }.map(|value| (value, ptr))
+    }
+
+    /// Returns a copyable [`ChildRef`] handle on this [`MonoBox`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn child(&self) -> ChildRef<'_, T> {
+        Guard::new(self)
+    }
+
     /// Gets the value stored in this [`MonoBox`], if any.
     #[cfg_attr(not(tarpaulin), inline(always))]
     pub fn as_mut(&mut self) -> Option<&mut T> {
@@ -114,6 +381,130 @@ impl<T> MonoBox<T> {
         unsafe { ptr.as_mut() }
     }
 
+    /// Applies `f` to the value stored in this [`MonoBox`], if any,
+    /// and returns the mutable reference it projects, e.g., to a
+    /// field; returns [`None`] if the [`MonoBox`] is empty.  This is
+    /// handy to finish initialising part of a value before sharing
+    /// the [`MonoBox`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn map_mut<U>(&mut self, f: impl FnOnce(&mut T) -> &mut U) -> Option<&mut U> {
+        self.as_mut().map(f)
+    }
+
+    /// Returns an iterator that yields a mutable reference to the
+    /// value stored in this [`MonoBox`], if any, i.e., zero or one
+    /// item.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.as_mut().into_iter()
+    }
+
+    /// Gets the value stored in this [`MonoBox`], or attempts to
+    /// initialise it with the result of `f` if the [`MonoBox`] is
+    /// empty.
+    ///
+    /// When `f` returns [`Ok`], we try to store its value, and return
+    /// a reference to whichever value ended up in the [`MonoBox`]:
+    /// if another thread won the race, our value is dropped.  When `f`
+    /// returns [`Err`], the [`MonoBox`] is left as is and the error
+    /// is passed back.
+    ///
+    /// Initialisation isn't serialised: concurrent callers that find
+    /// the [`MonoBox`] empty may all run their `f`.
+    pub fn get_or_try_init<E>(&self, f: impl FnOnce() -> Result<Box<T>, E>) -> Result<&T, E> {
+        if let Some(value) = self.as_ref() {
+            return Ok(value);
+        }
+
+        // If we lost the race, drop our value and use the winner's.
+        let _ = self.store(f()?);
+        Ok(self
+            .as_ref()
+            .expect("MonoBox must be populated after a store"))
+    }
+
+    /// Gets the value stored in this [`MonoBox`], or initialises it
+    /// with the result of `compute` if the [`MonoBox`] is empty.
+    ///
+    /// This is [`MonoBox::get_or_try_init`] for infallible
+    /// initialisers that return a plain `T`.  Concurrent callers that
+    /// find the [`MonoBox`] empty may all run their `compute`, but
+    /// they all return a reference to the same value: losers' values
+    /// are dropped.
+    pub fn get_or_compute(&self, compute: impl FnOnce() -> T) -> &T {
+        if let Some(value) = self.as_ref() {
+            return value;
+        }
+
+        // If we lost the race, drop our value and use the winner's.
+        self.store_discarding(Box::new(compute()));
+        self.as_ref()
+            .expect("MonoBox must be populated after a store")
+    }
+
+    /// Calls `f` with the pointer to the value stored in this
+    /// [`MonoBox`], or with a null pointer if the [`MonoBox`] is
+    /// empty, and returns `f`'s result.
+    ///
+    /// This gives access to the value's address without creating a
+    /// reference to the value itself.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn with_raw<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+        f(self.ptr_or_null.load(Ordering::Acquire))
+    }
+
+    /// Returns the raw state of this [`MonoBox`], read with a
+    /// `Relaxed` load, for logging.
+    pub fn debug_state(&self) -> ContainerDebug {
+        let ptr = self.ptr_or_null.load(Ordering::Relaxed);
+
+        ContainerDebug {
+            ptr_addr: ptr as usize,
+            populated: !ptr.is_null(),
+        }
+    }
+
+    /// Returns whether this [`MonoBox`] and `other` are both empty,
+    /// or both hold equal values.  Two empty [`MonoBox`]es count as
+    /// equal.
+    ///
+    /// This is currently the same predicate as [`PartialEq`], but
+    /// spells out the treatment of empty containers at call sites.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn value_eq(&self, other: &MonoBox<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_ref() == other.as_ref()
+    }
+
+    /// Applies `f` to the value stored in this [`MonoBox`], if any,
+    /// and returns the result; returns [`None`] if the [`MonoBox`] is
+    /// empty.  This is [`Option::map`] over [`MonoBox::as_ref`]: the
+    /// [`MonoBox`] keeps its value.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn map<U>(&self, f: impl FnOnce(&T) -> U) -> Option<U> {
+        self.as_ref().map(f)
+    }
+
+    /// Returns a fresh [`MonoBox`] that holds a copy of this
+    /// [`MonoBox`]'s value, as constructed by `cloner`, or
+    /// [`None`] if this [`MonoBox`] is empty.
+    ///
+    /// This is useful when `T` doesn't implement [`Clone`], e.g., for
+    /// trait objects with their own cloning method.
+    pub fn try_clone_with(&self, cloner: impl FnOnce(&T) -> Box<T>) -> MonoBox<T> {
+        MonoBox::new(self.as_ref().map(cloner))
+    }
+
+    /// Consumes this [`MonoBox`], and returns a [`MonoArc`] that holds
+    /// the same value, if any.  This is the same conversion as
+    /// [`MonoArc::from_box_moving`], as a method.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_mono_arc(self) -> MonoArc<T> {
+        MonoArc::from_box_moving(self)
+    }
+
     /// Takes the value out of this [`MonoBox`], leaving a [`None`] in
     /// its place.
     #[cfg_attr(not(tarpaulin), inline(always))]
@@ -124,13 +515,52 @@ impl<T> MonoBox<T> {
     /// Consumes this [`MonoBox`], returning the wrapped value, if
     /// any.
     #[cfg_attr(not(tarpaulin), inline(always))]
-    pub fn into_inner(mut self) -> Option<Box<T>> {
-        self.take()
+    pub fn into_inner(self) -> Option<Box<T>> {
+        // There's no point writing a null pointer back to a
+        // container we're about to destroy: read the pointer and
+        // skip `Drop` instead of calling `take()`.
+        // Owning `self` excludes concurrent writers, so `Relaxed` is
+        // enough, as in `swap()`.
+        let this = core::mem::ManuallyDrop::new(self);
+        let ptr = this.ptr_or_null.load(Ordering::Relaxed);
+
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { Box::from_raw(ptr) })
+        }
+    }
+
+    /// Consumes this [`MonoBox`], returning the wrapped [`Box`], if
+    /// any.  This is the same as [`MonoBox::into_inner`], which
+    /// already reads the pointer and skips the container's [`Drop`]
+    /// without storing a null pointer back.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn unwrap_box(self) -> Option<Box<T>> {
+        self.into_inner()
+    }
+}
+
+impl<T: Default> MonoBox<T> {
+    /// Takes the value out of this [`MonoBox`], leaving a [`None`] in
+    /// its place.  Returns a freshly boxed `T::default()` if the
+    /// [`MonoBox`] was empty.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn take_or_default(&mut self) -> Box<T> {
+        self.take().unwrap_or_default()
     }
 }
 
 impl<T> Drop for MonoBox<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
     fn drop(&mut self) {
+        // Large tables of empty containers are common: bail with a
+        // plain `Relaxed` load (`&mut` excludes concurrent writers)
+        // before going through `take()`.
+        if self.is_none() {
+            return;
+        }
+
         core::mem::drop(self.take())
     }
 }
@@ -148,6 +578,43 @@ impl<T: core::fmt::Debug> core::fmt::Debug for MonoBox<T> {
     }
 }
 
+/// [`MonoBox`]s compare like the [`Option<&T>`] returned by
+/// [`MonoBox::as_ref`].  Storing to an empty [`MonoBox`] changes its
+/// equality (and hash), so empty [`MonoBox`]s used as map keys must
+/// not be populated while in the map.
+impl<T: PartialEq> PartialEq for MonoBox<T> {
+    fn eq(&self, other: &MonoBox<T>) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T: Eq> Eq for MonoBox<T> {}
+
+/// [`MonoBox`]s hash like the [`Option<&T>`] returned by
+/// [`MonoBox::as_ref`].
+impl<T: core::hash::Hash> core::hash::Hash for MonoBox<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+/// Appends to the stored [`String`](alloc::string::String), e.g.,
+/// with [`write!`].  Writes need a `&mut` reference (i.e., exclusive
+/// ownership), and populate an empty [`MonoBox`] with an empty string
+/// first.
+impl core::fmt::Write for MonoBox<alloc::string::String> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.is_none() {
+            self.swap(Some(Box::default()));
+        }
+
+        self.as_mut()
+            .expect("MonoBox must be populated")
+            .push_str(s);
+        Ok(())
+    }
+}
+
 impl<T> core::fmt::Pointer for MonoBox<T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         core::fmt::Pointer::fmt(&(self.ptr_or_null.load(Ordering::Relaxed) as *const T), f)
@@ -170,14 +637,14 @@ impl<T: core::ops::DerefMut> MonoBox<T> {
 
 impl<'a, T> From<&'a MonoBox<T>> for Option<&'a T> {
     #[cfg_attr(not(tarpaulin), inline(always))]
-    fn from(mono: &'a MonoBox<T>) -> Option<&T> {
+    fn from(mono: &'a MonoBox<T>) -> Option<&'a T> {
         mono.as_ref()
     }
 }
 
 impl<'a, T> From<&'a mut MonoBox<T>> for Option<&'a mut T> {
     #[cfg_attr(not(tarpaulin), inline(always))]
-    fn from(mono: &'a mut MonoBox<T>) -> Option<&mut T> {
+    fn from(mono: &'a mut MonoBox<T>) -> Option<&'a mut T> {
         mono.as_mut()
     }
 }
@@ -290,6 +757,85 @@ fn test_drop() {
     assert_eq!(counter.load(Ordering::Relaxed), 2);
 }
 
+#[test]
+fn test_store_discarding() {
+    use std::sync::atomic::AtomicUsize;
+
+    struct DropTracker<'a> {
+        counter: &'a AtomicUsize,
+    }
+
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = AtomicUsize::new(0);
+    let mono = MonoBox::empty();
+
+    assert!(mono.store_discarding(Box::new(DropTracker { counter: &counter })));
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+    // Failed stores drop their value exactly once.
+    assert!(!mono.store_discarding(Box::new(DropTracker { counter: &counter })));
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+    assert!(!mono.store_value(DropTracker { counter: &counter }));
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+
+    // Same result as `store().is_ok()`.
+    let other = MonoBox::empty();
+    assert!(other
+        .store(Box::new(DropTracker { counter: &counter }))
+        .is_ok());
+    assert!(other
+        .store(Box::new(DropTracker { counter: &counter }))
+        .is_err());
+    assert_eq!(counter.load(Ordering::Relaxed), 3);
+
+    std::mem::drop(mono);
+    std::mem::drop(other);
+    assert_eq!(counter.load(Ordering::Relaxed), 5);
+}
+
+#[test]
+fn test_into_inner() {
+    use std::sync::atomic::AtomicUsize;
+
+    struct DropTracker<'a> {
+        value: usize,
+        counter: &'a AtomicUsize,
+    }
+
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = AtomicUsize::new(0);
+    let mono = MonoBox::new(Some(Box::new(DropTracker {
+        value: 42,
+        counter: &counter,
+    })));
+
+    let inner = mono.into_inner().unwrap();
+    assert_eq!(inner.value, 42);
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+    std::mem::drop(inner);
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+    let mut mono = MonoBox::new(Some(Box::new(DropTracker {
+        value: 1,
+        counter: &counter,
+    })));
+    std::mem::drop(mono.take());
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+    assert!(mono.into_inner().is_none());
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+}
+
 #[test]
 fn test_upgrade() {
     let mono: MonoBox<Vec<usize>> = Default::default();
@@ -312,6 +858,210 @@ fn test_store_value() {
     assert_eq!(mono.as_ref().unwrap(), &[1]);
 }
 
+#[test]
+fn test_store_into() {
+    let mono: MonoBox<Vec<usize>> = Default::default();
+
+    assert_eq!(mono.store_into(vec![1]), Ok(()));
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+
+    assert_eq!(mono.store_into(Box::new(vec![2])), Err(Box::new(vec![2])));
+    assert_eq!(mono.store_into(vec![3]), Err(Box::new(vec![3])));
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+
+    let mono: MonoBox<Vec<usize>> = Default::default();
+
+    assert_eq!(mono.store_into(Box::new(vec![4])), Ok(()));
+    assert_eq!(mono.as_ref().unwrap(), &[4]);
+}
+
+#[test]
+fn test_store_hinted() {
+    let mono: MonoBox<Vec<usize>> = Default::default();
+
+    assert_eq!(mono.store_hinted(Box::new(vec![1])), Ok(()));
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+
+    assert_eq!(mono.store_hinted(Box::new(vec![2])), Err(Box::new(vec![2])));
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+}
+
+#[test]
+fn test_store_hinted_race() {
+    let mono: MonoBox<usize> = Default::default();
+
+    let wins: usize = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..8)
+            .map(|i| {
+                let mono = &mono;
+                s.spawn(move || mono.store_hinted(Box::new(i)).is_ok() as usize)
+            })
+            .collect();
+
+        workers.into_iter().map(|w| w.join().unwrap()).sum()
+    });
+
+    assert_eq!(wins, 1);
+    assert!(mono.is_some());
+}
+
+#[test]
+fn test_get_or_try_init() {
+    let mono: MonoBox<Vec<usize>> = Default::default();
+
+    assert_eq!(
+        mono.get_or_try_init(|| Err::<Box<Vec<usize>>, _>("failed")),
+        Err("failed")
+    );
+    assert!(mono.is_none());
+
+    assert_eq!(
+        mono.get_or_try_init(|| Ok::<_, ()>(Box::new(vec![1]))),
+        Ok(&vec![1])
+    );
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+
+    // Populated containers don't call `f` at all.
+    assert_eq!(
+        mono.get_or_try_init(|| -> Result<Box<Vec<usize>>, ()> { panic!("should not be called") }),
+        Ok(&vec![1])
+    );
+}
+
+#[test]
+fn test_get_or_try_init_race() {
+    use std::sync::Barrier;
+
+    let mono: MonoBox<usize> = Default::default();
+    let barrier = Barrier::new(8);
+
+    let values: Vec<*const usize> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..8)
+            .map(|i| {
+                let mono = &mono;
+                let barrier = &barrier;
+                s.spawn(move || {
+                    let value = mono
+                        .get_or_try_init(|| {
+                            barrier.wait();
+                            Ok::<_, ()>(Box::new(i))
+                        })
+                        .unwrap();
+                    value as *const usize as usize
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|w| w.join().unwrap() as *const usize)
+            .collect()
+    });
+
+    let winner = mono.as_ref().unwrap() as *const usize;
+    assert!(values.iter().all(|&value| value == winner));
+}
+
+#[test]
+fn test_store_counted() {
+    let mono: MonoBox<Vec<usize>> = Default::default();
+    let reference: MonoBox<Vec<usize>> = Default::default();
+
+    assert_eq!(mono.store_counted(Box::new(vec![1])), (Ok(()), 1));
+    assert_eq!(reference.store(Box::new(vec![1])), Ok(()));
+    assert_eq!(mono.as_ref(), reference.as_ref());
+
+    assert_eq!(
+        mono.store_counted(Box::new(vec![2])),
+        (Err(Box::new(vec![2])), 1)
+    );
+    assert_eq!(reference.store(Box::new(vec![2])), Err(Box::new(vec![2])));
+    assert_eq!(mono.as_ref(), reference.as_ref());
+}
+
+#[test]
+fn test_build_and_store() {
+    let mono: MonoBox<Vec<usize>> = Default::default();
+
+    let wins: usize = std::thread::scope(|s| {
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let mono = &mono;
+                s.spawn(move || loop {
+                    if let Some(value) = mono.as_ref() {
+                        assert_eq!(value.len(), 1000);
+                        assert!(value.iter().enumerate().all(|(i, x)| i == *x));
+                        break;
+                    }
+
+                    std::hint::spin_loop();
+                })
+            })
+            .collect();
+
+        let writers: Vec<_> = (0..2)
+            .map(|_| {
+                let mono = &mono;
+                s.spawn(move || {
+                    mono.build_and_store(|value| value.extend(0..1000), Vec::new())
+                        .map_err(|value| assert_eq!(value.len(), 1000))
+                        .is_ok() as usize
+                })
+            })
+            .collect();
+
+        for reader in readers {
+            reader.join().unwrap();
+        }
+
+        writers.into_iter().map(|w| w.join().unwrap()).sum()
+    });
+
+    assert_eq!(wins, 1);
+    assert_eq!(mono.as_ref().unwrap().len(), 1000);
+}
+
+#[test]
+fn test_store_returning_old() {
+    struct Node {
+        value: usize,
+        next: *mut Node,
+    }
+
+    let mut head: MonoBox<Node> = Default::default();
+
+    for value in 1..=3 {
+        let node = Box::into_raw(Box::new(Node {
+            value,
+            next: core::ptr::null_mut(),
+        }));
+        let node = core::ptr::NonNull::new(node).unwrap();
+
+        // No concurrent readers: we can link the new node after
+        // publishing it.
+        unsafe {
+            let old = head.store_returning_old(node);
+            (*node.as_ptr()).next = old.map_or(core::ptr::null_mut(), |old| old.as_ptr());
+        }
+    }
+
+    let mut values = Vec::new();
+    let mut node: *const Node = head.as_ref().unwrap();
+    while let Some(current) = unsafe { node.as_ref() } {
+        values.push(current.value);
+        node = current.next;
+    }
+
+    assert_eq!(values, [3, 2, 1]);
+
+    // Free the whole stack.
+    let mut node = head.take().map(Box::into_raw).unwrap();
+    while !node.is_null() {
+        let current = unsafe { Box::from_raw(node) };
+        node = current.next;
+    }
+}
+
 #[test]
 fn test_swap() {
     let mut mono: MonoBox<Vec<usize>> = Default::default();
@@ -331,6 +1081,89 @@ fn test_swap() {
     assert_eq!(mono.take(), None);
 }
 
+#[test]
+fn test_take_or_default() {
+    let mut mono: MonoBox<Vec<usize>> = vec![1].into();
+
+    assert_eq!(mono.take_or_default(), Box::new(vec![1]));
+    assert!(mono.is_none());
+
+    assert_eq!(mono.take_or_default(), Box::new(vec![]));
+    assert!(mono.is_none());
+}
+
+#[test]
+fn test_with_raw() {
+    let mono: MonoBox<usize> = Default::default();
+
+    assert!(mono.with_raw(|ptr| ptr.is_null()));
+
+    assert_eq!(mono.store(Box::new(42)), Ok(()));
+    let expected = mono.as_ref().unwrap() as *const usize;
+    assert_eq!(mono.with_raw(|ptr| ptr), expected);
+    assert_eq!(mono.with_raw(|ptr| unsafe { *ptr }), 42);
+}
+
+#[test]
+fn test_try_clone_with() {
+    let empty = MonoBox::<Vec<usize>>::empty();
+    assert!(empty
+        .try_clone_with(|_| panic!("should not be called"))
+        .is_none());
+
+    let mono: MonoBox<Vec<usize>> = vec![1].into();
+    let mut copy = mono.try_clone_with(|value| Box::new(value.clone()));
+
+    assert_eq!(copy.as_ref().unwrap(), &[1]);
+    assert_ne!(
+        copy.as_ref().unwrap() as *const _,
+        mono.as_ref().unwrap() as *const _
+    );
+
+    copy.as_mut().unwrap().push(2);
+    assert_eq!(copy.as_ref().unwrap(), &[1, 2]);
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+}
+
+#[test]
+fn test_scoped_store() {
+    let mut mono: MonoBox<Vec<usize>> = Default::default();
+
+    {
+        let scoped = mono.scoped_store(Box::new(vec![1]));
+        assert_eq!(scoped.as_ref().unwrap(), &[1]);
+    }
+    assert!(mono.is_none());
+
+    let scoped = mono.scoped_store(Box::new(vec![2]));
+    assert_eq!(scoped.commit(), None);
+    assert_eq!(mono.as_ref().unwrap(), &[2]);
+
+    {
+        let scoped = mono.scoped_store(Box::new(vec![3]));
+        assert_eq!(scoped.as_ref().unwrap(), &[3]);
+    }
+    assert_eq!(mono.as_ref().unwrap(), &[2]);
+
+    let scoped = mono.scoped_store(Box::new(vec![4]));
+    assert_eq!(scoped.commit(), Some(Box::new(vec![2])));
+    assert_eq!(mono.as_ref().unwrap(), &[4]);
+}
+
+#[test]
+fn test_iter_mut() {
+    let mut empty = MonoBox::<Vec<usize>>::empty();
+    assert_eq!(empty.iter_mut().count(), 0);
+
+    let mut mono: MonoBox<Vec<usize>> = vec![1].into();
+    for value in mono.iter_mut() {
+        value.push(2);
+    }
+
+    assert_eq!(mono.iter_mut().count(), 1);
+    assert_eq!(mono.as_ref().unwrap(), &[1, 2]);
+}
+
 #[test]
 fn test_fmt() {
     let mono = MonoBox::<()>::empty();
@@ -339,6 +1172,59 @@ fn test_fmt() {
     assert_eq!(format!("as a pointer: {:p}", mono), "as a pointer: 0x0");
 }
 
+#[test]
+fn test_hash() {
+    fn hash<T: core::hash::Hash>(value: &T) -> u64 {
+        use std::hash::Hasher;
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let empty = MonoBox::<String>::empty();
+    assert_eq!(hash(&empty), hash(&Option::<&String>::None));
+
+    let mono: MonoBox<String> = "foo".to_string().into();
+    assert_eq!(hash(&mono), hash(&Some(&"foo".to_string())));
+    assert_ne!(hash(&mono), hash(&empty));
+}
+
+#[test]
+fn test_derive() {
+    use std::collections::HashMap;
+
+    // Containers work as fields of derived keys, and behave like
+    // `Option<T>` fields would.
+    #[derive(Debug, Hash, PartialEq, Eq)]
+    struct Key {
+        name: MonoBox<String>,
+        id: MonoArc<u32>,
+    }
+
+    let key = |name: Option<&str>, id: Option<u32>| Key {
+        name: name.map(String::from).into(),
+        id: id.into(),
+    };
+
+    // Empty fields could be populated through shared references, so
+    // clippy flags `Key` as mutable; we never store to keys in the map.
+    #[allow(clippy::mutable_key_type)]
+    let mut map = HashMap::new();
+    map.insert(key(Some("foo"), Some(1)), 1);
+    map.insert(key(Some("foo"), None), 2);
+    map.insert(key(None, None), 3);
+
+    assert_eq!(map.get(&key(Some("foo"), Some(1))), Some(&1));
+    assert_eq!(map.get(&key(Some("foo"), None)), Some(&2));
+    assert_eq!(map.get(&key(None, None)), Some(&3));
+    assert_eq!(map.get(&key(Some("foo"), Some(2))), None);
+    assert_eq!(map.get(&key(None, Some(1))), None);
+
+    assert_eq!(MonoBox::from(1), MonoBox::from(1));
+    assert_ne!(MonoBox::from(1), MonoBox::empty());
+}
+
 #[test]
 fn test_conversions() {
     let mono: MonoBox<_> = Option::<String>::None.into();
@@ -377,12 +1263,24 @@ fn test_conversions() {
     assert_eq!(val, Some(Box::new("baz".to_string())));
 
     let boxed = MonoBox::<String>::empty();
+    #[allow(clippy::useless_conversion)]
     let mono: MonoBox<String> = boxed.into();
     assert!(mono.is_none());
 
     let _val: Option<String> = mono.into();
 }
 
+#[test]
+fn test_into_mono_arc() {
+    let mono = MonoBox::<String>::empty().into_mono_arc();
+    assert!(mono.is_none());
+
+    let boxed: MonoBox<String> = "foo".to_string().into();
+    let mono = boxed.into_mono_arc();
+    assert_eq!(mono.as_deref(), Some("foo"));
+    assert_eq!(mono.get().map(|arc| arc.len()), Some(3));
+}
+
 #[test]
 fn test_mut_conversions() {
     let mut mono: MonoBox<Vec<u8>> = Default::default();
@@ -404,3 +1302,335 @@ fn test_mut_conversions() {
     let as_mut: Option<&mut Vec<u8>> = (&mut mono).into();
     assert_eq!(as_mut, Some(&mut vec));
 }
+
+#[test]
+fn test_map() {
+    let mono = MonoBox::<String>::empty();
+    assert_eq!(mono.map(|x| x.len()), None);
+
+    let mono: MonoBox<String> = "foo".to_string().into();
+    assert_eq!(mono.map(|x| x.len()), Some(3));
+    assert_eq!(mono.map(|x| x.to_uppercase()), Some("FOO".to_string()));
+    // The value is still there.
+    assert_eq!(mono.as_ref().map(String::as_str), Some("foo"));
+}
+
+#[test]
+fn test_set_unsynchronized() {
+    use std::sync::Arc;
+
+    let table: Vec<MonoBox<usize>> = (0..100).map(|_| MonoBox::empty()).collect();
+    for (i, cell) in table.iter().enumerate() {
+        unsafe { cell.set_unsynchronized(Box::new(i)) };
+    }
+
+    std::sync::atomic::fence(std::sync::atomic::Ordering::Release);
+    let table = Arc::new(table);
+
+    let reader = {
+        let table = table.clone();
+        std::thread::spawn(move || {
+            std::sync::atomic::fence(std::sync::atomic::Ordering::Acquire);
+            for (i, cell) in table.iter().enumerate() {
+                assert_eq!(cell.as_ref(), Some(&i));
+                // Unsynchronized fills still count as populated.
+                assert_eq!(cell.store(Box::new(0)), Err(Box::new(0)));
+            }
+        })
+    };
+
+    reader.join().expect("reader should succeed");
+    assert_eq!(table[42].as_ref(), Some(&42));
+}
+
+#[test]
+fn test_child() {
+    struct Node {
+        value: usize,
+        next: MonoBox<Node>,
+    }
+
+    let head: MonoBox<Node> = MonoBox::empty();
+    let mut tail = head.child();
+    assert_eq!(format!("{:?}", MonoBox::<usize>::empty().child()), "None");
+    let mut cell = &head;
+    for value in 0..4 {
+        assert!(cell.store_value(Node {
+            value,
+            next: MonoBox::empty(),
+        }));
+        cell = &cell.as_ref().unwrap().next;
+    }
+
+    // `tail` was created before any store, and sees them all.
+    assert_eq!(tail.get().map(|node| node.value), Some(0));
+
+    let mut seen = Vec::new();
+    let mut cursor = head.child();
+    while let Some(node) = cursor.get() {
+        seen.push(node.value);
+        cursor = node.next.child();
+    }
+    assert_eq!(seen, [0, 1, 2, 3]);
+
+    // Walk to the end, then extend the list through the last handle's
+    // container.
+    while let Some(node) = tail.get() {
+        tail = node.next.child();
+    }
+    assert!(tail.get().is_none());
+    assert!(cell.store_value(Node {
+        value: 4,
+        next: MonoBox::empty(),
+    }));
+    assert_eq!(tail.get().map(|node| node.value), Some(4));
+}
+
+#[test]
+fn test_debug_state() {
+    let mono = MonoBox::<usize>::empty();
+    assert_eq!(mono.debug_state(), ContainerDebug::default());
+
+    assert!(mono.store_value(1));
+    let state = mono.debug_state();
+    assert!(state.populated);
+    assert_eq!(
+        state.ptr_addr,
+        mono.as_ref().unwrap() as *const usize as usize
+    );
+}
+
+#[test]
+fn test_fmt_write() {
+    use std::fmt::Write;
+
+    let mut mono = MonoBox::<String>::empty();
+    let two = "two";
+    write!(&mut mono, "{}-{}", 1, two).unwrap();
+    assert_eq!(mono.as_deref(), Some("1-two"));
+
+    writeln!(mono, "!").unwrap();
+    assert_eq!(mono.as_deref(), Some("1-two!\n"));
+}
+
+#[test]
+fn test_panic_safety() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mono = MonoBox::<usize>::empty();
+
+    // A panicking initialiser leaves the `MonoBox` empty and usable.
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        let _ = mono.get_or_try_init(|| -> Result<Box<usize>, ()> { panic!("init failed") });
+    }))
+    .is_err());
+    assert!(mono.is_none());
+
+    // So does a panic while building a value.
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        let _ = mono.build_and_store(|_| panic!("build failed"), 1);
+    }))
+    .is_err());
+    assert!(mono.is_none());
+
+    assert_eq!(mono.get_or_try_init(|| Ok::<_, ()>(Box::new(2))), Ok(&2));
+}
+
+#[test]
+fn test_get_or_compute() {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Barrier;
+
+    const THREADS: usize = 4;
+
+    struct DropTracker<'a> {
+        id: usize,
+        counter: &'a AtomicUsize,
+    }
+
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = AtomicUsize::new(0);
+    let computed = AtomicUsize::new(0);
+    let barrier = Barrier::new(THREADS);
+    let mono = MonoBox::empty();
+
+    let seen: Vec<(usize, usize)> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..THREADS)
+            .map(|id| {
+                let (mono, counter, computed, barrier) = (&mono, &counter, &computed, &barrier);
+                s.spawn(move || {
+                    barrier.wait();
+                    let value = mono.get_or_compute(|| {
+                        computed.fetch_add(1, Ordering::Relaxed);
+                        DropTracker { id, counter }
+                    });
+                    (value as *const DropTracker as usize, value.id)
+                })
+            })
+            .collect();
+
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    assert!(seen.iter().all(|x| *x == seen[0]));
+    assert_eq!(mono.as_ref().map(|x| x.id), Some(seen[0].1));
+    // Every computed value but the winner was dropped.
+    assert_eq!(
+        counter.load(Ordering::Relaxed),
+        computed.load(Ordering::Relaxed) - 1
+    );
+
+    // Populated `MonoBox`es don't compute anything.
+    mono.get_or_compute(|| panic!("should not be called"));
+}
+
+#[test]
+fn test_unwrap_box() {
+    use std::sync::atomic::AtomicUsize;
+
+    struct DropTracker<'a> {
+        value: usize,
+        counter: &'a AtomicUsize,
+    }
+
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = AtomicUsize::new(0);
+    let make = |value| {
+        MonoBox::from(DropTracker {
+            value,
+            counter: &counter,
+        })
+    };
+
+    assert!(MonoBox::<usize>::empty().unwrap_box().is_none());
+
+    let unwrapped = make(1).unwrap_box().expect("populated");
+    let inner = make(1).into_inner().expect("populated");
+    assert_eq!(unwrapped.value, inner.value);
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+    std::mem::drop(unwrapped);
+    std::mem::drop(inner);
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn test_as_ref_and_ptr() {
+    let mono = MonoBox::<usize>::empty();
+    assert_eq!(mono.as_ref_and_ptr(), None);
+
+    assert!(mono.store_value(1));
+    let (value, ptr) = mono.as_ref_and_ptr().expect("populated");
+    assert_eq!(*value, 1);
+    assert!(core::ptr::eq(value, ptr));
+    assert!(mono.with_raw(|raw| raw == ptr));
+}
+
+#[test]
+fn test_get() {
+    let mono = MonoBox::<usize>::empty();
+    assert_eq!(mono.get(), None);
+
+    assert!(mono.store_value(1));
+    assert_eq!(mono.get(), Some(&1));
+    assert_eq!(mono.get(), mono.as_ref());
+}
+
+#[test]
+fn test_as_non_null() {
+    let mono = MonoBox::<usize>::empty();
+    assert_eq!(mono.as_non_null(), None);
+
+    assert!(mono.store_value(1));
+    let ptr = mono.as_non_null().expect("populated");
+    assert_eq!(unsafe { *ptr.as_ptr() }, 1);
+    assert!(core::ptr::eq(ptr.as_ptr(), mono.as_ref().unwrap()));
+}
+
+#[test]
+fn test_map_mut() {
+    struct Config {
+        name: String,
+        retries: usize,
+    }
+
+    let mut mono = MonoBox::<Config>::empty();
+    assert!(mono.map_mut(|config| &mut config.retries).is_none());
+
+    assert!(mono.store_value(Config {
+        name: "foo".to_string(),
+        retries: 0,
+    }));
+    *mono.map_mut(|config| &mut config.retries).unwrap() = 3;
+    mono.map_mut(|config| &mut config.name)
+        .unwrap()
+        .push_str("bar");
+
+    let config = mono.as_ref().unwrap();
+    assert_eq!(config.retries, 3);
+    assert_eq!(config.name, "foobar");
+}
+
+#[test]
+fn test_value_eq() {
+    let empty = MonoBox::<usize>::empty();
+    let one = MonoBox::from(1);
+
+    assert!(empty.value_eq(&MonoBox::empty()));
+    assert!(one.value_eq(&MonoBox::from(1)));
+    assert!(!one.value_eq(&MonoBox::from(2)));
+    assert!(!one.value_eq(&empty));
+    assert!(!empty.value_eq(&one));
+}
+
+#[test]
+fn test_layout() {
+    use core::mem::{align_of, size_of};
+
+    const _: () = assert!(size_of::<MonoBox<u64>>() == size_of::<AtomicPtr<u64>>());
+    const _: () = assert!(align_of::<MonoBox<u64>>() == align_of::<AtomicPtr<u64>>());
+
+    let mono: MonoBox<u64> = Default::default();
+    let atomic = unsafe { &*(&mono as *const MonoBox<u64> as *const AtomicPtr<u64>) };
+    assert!(atomic.load(Ordering::Acquire).is_null());
+
+    let raw = Box::into_raw(Box::new(1u64));
+    assert!(atomic
+        .compare_exchange(
+            core::ptr::null_mut(),
+            raw,
+            Ordering::Release,
+            Ordering::Relaxed
+        )
+        .is_ok());
+    assert_eq!(mono.as_ref(), Some(&1));
+    assert_eq!(mono.into_inner(), Some(Box::new(1)));
+}
+
+#[test]
+fn test_store_first() {
+    use std::cell::Cell;
+
+    let consumed = Cell::new(0);
+    let lazy = || (1..).inspect(|_: &usize| consumed.set(consumed.get() + 1));
+
+    let mono = MonoBox::<usize>::empty();
+    assert!(!mono.store_first(core::iter::empty()));
+    assert!(mono.store_first(lazy()));
+    assert_eq!(mono.as_ref(), Some(&1));
+    assert_eq!(consumed.get(), 1);
+
+    // Populated `MonoBox`es don't even advance the iterator.
+    assert!(!mono.store_first(lazy()));
+    assert_eq!(consumed.get(), 1);
+}
@@ -0,0 +1,143 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+use core::ptr::NonNull;
+
+use crate::MonoBox;
+
+/// An [`EpochBox<T>`] is a [`MonoBox<T>`] that may be republished
+/// through a shared reference, for users of epoch-based reclamation
+/// (EBR) schemes.  This is a deliberate escape from write-once
+/// semantics.
+///
+/// [`EpochBox::publish`] atomically replaces the current value, and
+/// hands the previous one back to the caller as a raw pointer,
+/// without freeing it: readers may still hold references obtained
+/// with [`EpochBox::load`].  The caller must defer freeing (or
+/// otherwise mutating) the old value until all readers from the
+/// previous epoch are gone, and then release it with
+/// [`Box::from_raw`].  Never freeing retired values is safe, but
+/// leaks.
+pub struct EpochBox<T> {
+    mono: MonoBox<T>,
+}
+
+impl<T> EpochBox<T> {
+    /// Returns a fresh [`EpochBox`] that holds `inner`.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn new(inner: Option<Box<T>>) -> Self {
+        Self {
+            mono: MonoBox::new(inner),
+        }
+    }
+
+    /// Returns a fresh [`EpochBox`] that holds [`None`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn empty() -> Self {
+        Self::new(None)
+    }
+
+    /// Gets the value currently published in this [`EpochBox`], if
+    /// any.
+    ///
+    /// The reference lives as long as the [`EpochBox`], but the value
+    /// may be retired by a concurrent [`EpochBox::publish`]: it's up
+    /// to the reclamation scheme to keep retired values alive for as
+    /// long as readers use them.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn load(&self) -> Option<&T> {
+        self.mono.as_ref()
+    }
+
+    /// Publishes `value` in this [`EpochBox`], and returns the
+    /// previous value, if any.
+    ///
+    /// The previous value is retired, not freed: the caller owns it,
+    /// and must only free it (with [`Box::from_raw`]) once no reader
+    /// can still be using a reference from [`EpochBox::load`].
+    pub fn publish(&self, value: Box<T>) -> Option<NonNull<T>> {
+        // `store_returning_old` hands us ownership of the old pointer,
+        // which we pass on to the caller, without creating a `Box`
+        // (that would assert uniqueness while readers may still hold
+        // references).
+        unsafe {
+            self.mono
+                .store_returning_old(NonNull::from(Box::leak(value)))
+        }
+    }
+
+    /// Consumes this [`EpochBox`], returning the currently published
+    /// value, if any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_inner(self) -> Option<Box<T>> {
+        self.mono.into_inner()
+    }
+}
+
+impl<T> Default for EpochBox<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for EpochBox<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.load(), f)
+    }
+}
+
+#[test]
+fn test_publish() {
+    let epoch = EpochBox::<usize>::empty();
+    assert_eq!(epoch.load(), None);
+    assert!(epoch.publish(Box::new(1)).is_none());
+
+    let first = epoch.load().unwrap();
+    let retired = epoch.publish(Box::new(2)).expect("populated");
+    // Retired values stay valid until the caller frees them.
+    assert_eq!(*first, 1);
+    assert!(core::ptr::eq(retired.as_ptr(), first));
+    assert_eq!(format!("{:?}", epoch), "Some(2)");
+
+    core::mem::drop(unsafe { Box::from_raw(retired.as_ptr()) });
+    assert_eq!(epoch.into_inner(), Some(Box::new(2)));
+}
+
+#[test]
+fn test_single_writer_many_readers() {
+    const EPOCHS: usize = 100;
+    const READERS: usize = 3;
+
+    // Each value knows its epoch twice, to detect use-after-free.
+    let epoch: EpochBox<(usize, usize)> = EpochBox::new(Some(Box::new((0, 0))));
+    let mut retired = Vec::new();
+
+    std::thread::scope(|s| {
+        for _ in 0..READERS {
+            let epoch = &epoch;
+            s.spawn(move || {
+                let mut last = 0;
+                for _ in 0..1000 {
+                    let &(a, b) = epoch.load().expect("always populated");
+                    assert_eq!(a, b);
+                    // Readers observe epochs in order.
+                    assert!(a >= last);
+                    last = a;
+                }
+            });
+        }
+
+        for i in 1..=EPOCHS {
+            retired.extend(epoch.publish(Box::new((i, i))));
+        }
+    });
+
+    // All readers are gone: it's now safe to free retired values.
+    assert_eq!(retired.len(), EPOCHS);
+    for ptr in retired {
+        core::mem::drop(unsafe { Box::from_raw(ptr.as_ptr()) });
+    }
+
+    assert_eq!(epoch.load(), Some(&(EPOCHS, EPOCHS)));
+}
@@ -0,0 +1,186 @@
+extern crate alloc;
+
+use alloc::sync::Arc;
+
+use crate::ordering as Ordering;
+use crate::sync::AtomicU64;
+use crate::MonoArc;
+
+/// A [`MonoArcGen<T>`] is a [`MonoArc<T>`] paired with a generation
+/// counter that increases every time the container's value changes,
+/// i.e., on every successful [`MonoArcGen::store`], and on every
+/// [`MonoArcGen::swap`] (or [`MonoArcGen::take`]).
+///
+/// The generation is a cache invalidation key for containers that are
+/// reset and refilled under `&mut` references: when the generation
+/// hasn't changed, neither has the value.  The converse doesn't hold:
+/// [`MonoArcGen::get_with_gen`] may pair a freshly stored value with
+/// the previous generation, and thus cause spurious invalidations,
+/// but never a stale read.
+///
+/// The generation is an [`AtomicU64`](core::sync::atomic::AtomicU64),
+/// so [`MonoArcGen`] is only available on targets with 64-bit
+/// atomics.
+pub struct MonoArcGen<T> {
+    mono: MonoArc<T>,
+    generation: AtomicU64,
+}
+
+impl<T> MonoArcGen<T> {
+    /// Returns a fresh [`MonoArcGen`] that holds `inner`, at
+    /// generation 0.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn new(inner: Option<Arc<T>>) -> Self {
+        Self {
+            mono: MonoArc::new(inner),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns a fresh [`MonoArcGen`] that holds [`None`], at
+    /// generation 0.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn empty() -> Self {
+        Self::new(None)
+    }
+
+    /// Returns whether the [`MonoArcGen`]'s value is [`None`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn is_none(&self) -> bool {
+        self.mono.is_none()
+    }
+
+    /// Returns whether the [`MonoArcGen`]'s value is [`Some`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn is_some(&self) -> bool {
+        self.mono.is_some()
+    }
+
+    /// Returns the current generation.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Attempts to store `value` in this [`MonoArcGen`], and bumps
+    /// the generation on success.  See [`MonoArc::store`].
+    pub fn store(&self, value: Arc<T>) -> Result<(), Arc<T>> {
+        self.mono.store(value)?;
+        self.generation.fetch_add(1, Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the value previously stored in this [`MonoArcGen`],
+    /// replaces it with `value`, and bumps the generation.
+    pub fn swap(&mut self, value: Option<Arc<T>>) -> Option<Arc<T>> {
+        let old = self.mono.swap(value);
+
+        self.generation.fetch_add(1, Ordering::Release);
+        old
+    }
+
+    /// Takes the value out of this [`MonoArcGen`], leaving a [`None`]
+    /// in its place, and bumps the generation.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn take(&mut self) -> Option<Arc<T>> {
+        self.swap(None)
+    }
+
+    /// Gets the value stored in this [`MonoArcGen`], if any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn as_ref(&self) -> Option<&T> {
+        self.mono.as_ref()
+    }
+
+    /// Gets a clone of the [`Arc`] stored in this [`MonoArcGen`], if
+    /// any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn get(&self) -> Option<Arc<T>> {
+        self.mono.get()
+    }
+
+    /// Gets a clone of the [`Arc`] stored in this [`MonoArcGen`], if
+    /// any, along with a generation no later than the one at which
+    /// the value was stored.
+    pub fn get_with_gen(&self) -> Option<(u64, Arc<T>)> {
+        let value = self.mono.get()?;
+
+        Some((self.generation(), value))
+    }
+
+    /// Consumes this [`MonoArcGen`], returning the wrapped value, if
+    /// any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_inner(self) -> Option<Arc<T>> {
+        self.mono.into_inner()
+    }
+}
+
+impl<T> Default for MonoArcGen<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for MonoArcGen<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MonoArcGen")
+            .field("generation", &self.generation())
+            .field("value", &self.as_ref())
+            .finish()
+    }
+}
+
+impl<T> From<MonoArc<T>> for MonoArcGen<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(mono: MonoArc<T>) -> MonoArcGen<T> {
+        MonoArcGen {
+            mono,
+            generation: AtomicU64::new(0),
+        }
+    }
+}
+
+#[test]
+fn test_generation() {
+    let mut mono: MonoArcGen<Vec<usize>> = Default::default();
+
+    assert_eq!(mono.generation(), 0);
+    assert_eq!(mono.get_with_gen(), None);
+
+    assert_eq!(mono.store(Arc::new(vec![1])), Ok(()));
+    assert_eq!(mono.generation(), 1);
+    assert_eq!(mono.get_with_gen(), Some((1, Arc::new(vec![1]))));
+
+    // Failed stores leave the generation alone.
+    assert_eq!(mono.store(Arc::new(vec![2])), Err(Arc::new(vec![2])));
+    assert_eq!(mono.generation(), 1);
+
+    // Reset and refill.
+    assert_eq!(mono.take(), Some(Arc::new(vec![1])));
+    assert_eq!(mono.generation(), 2);
+    assert!(mono.is_none());
+    assert_eq!(mono.get_with_gen(), None);
+
+    assert_eq!(mono.store(Arc::new(vec![3])), Ok(()));
+    assert_eq!(mono.get_with_gen(), Some((3, Arc::new(vec![3]))));
+
+    assert_eq!(mono.swap(Some(Arc::new(vec![4]))), Some(Arc::new(vec![3])));
+    assert_eq!(mono.get_with_gen(), Some((4, Arc::new(vec![4]))));
+    assert_eq!(mono.as_ref().unwrap(), &[4]);
+
+    assert_eq!(
+        format!("{:?}", mono),
+        "MonoArcGen { generation: 4, value: Some([4]) }"
+    );
+    assert_eq!(mono.into_inner(), Some(Arc::new(vec![4])));
+}
+
+#[test]
+fn test_from_mono_arc() {
+    let mono: MonoArcGen<usize> = MonoArc::from(1).into();
+
+    assert!(mono.is_some());
+    assert_eq!(mono.get_with_gen(), Some((0, Arc::new(1))));
+}
@@ -0,0 +1,65 @@
+use crate::MonoArc;
+use crate::MonoBox;
+
+/// Containers that [`Guard`]s may wrap: once a read returns a value,
+/// that value is frozen for as long as the container is borrowed.
+pub trait Guarded<T> {
+    /// Gets the value currently stored in the container, if any.
+    fn read(&self) -> Option<&T>;
+}
+
+impl<T> Guarded<T> for MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn read(&self) -> Option<&T> {
+        self.as_ref()
+    }
+}
+
+impl<T> Guarded<T> for MonoBox<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn read(&self) -> Option<&T> {
+        self.as_ref()
+    }
+}
+
+/// A [`Guard`] is a copyable handle on a [`MonoArc`] (by default) or
+/// [`MonoBox`], returned by [`MonoArc::guard`] and [`MonoBox::child`].
+/// Its [`Guard::get`] method reads the container anew on each call,
+/// and returns references that live as long as the container itself,
+/// rather than as long as the [`Guard`].
+pub struct Guard<'a, T, C = MonoArc<T>> {
+    mono: &'a C,
+    _value: core::marker::PhantomData<&'a T>,
+}
+
+impl<'a, T, C: Guarded<T>> Guard<'a, T, C> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub(crate) fn new(mono: &'a C) -> Self {
+        Guard {
+            mono,
+            _value: core::marker::PhantomData,
+        }
+    }
+
+    /// Gets the value currently stored in the underlying container,
+    /// if any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn get(&self) -> Option<&'a T> {
+        self.mono.read()
+    }
+}
+
+impl<T, C> Clone for Guard<'_, T, C> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, C> Copy for Guard<'_, T, C> {}
+
+impl<T: core::fmt::Debug, C: Guarded<T>> core::fmt::Debug for Guard<'_, T, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.get(), f)
+    }
+}
@@ -0,0 +1,385 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+
+use crate::ordering as Ordering;
+use crate::sync::AtomicU8;
+use crate::MonoBox;
+
+// States for `MonoInit::state`.
+const EMPTY: u8 = 0;
+const INITIALIZING: u8 = 1;
+const ERRORED: u8 = 2;
+const DONE: u8 = 3;
+
+/// A [`MonoInit<T, E>`] is a [`MonoBox<T>`] with serialised, fallible,
+/// initialisation: unlike [`MonoBox::get_or_try_init`], only one
+/// thread at a time runs its initialiser, while other callers spin
+/// until that thread is done.
+///
+/// What happens after a failed initialisation depends on the error
+/// policy chosen at construction time:
+///
+///  - by default ([`MonoInit::new`]), errors are transient: the
+///    [`MonoInit`] returns to the empty state, and the next caller
+///    retries with its own initialiser;
+///  - with [`MonoInit::sticky`], the first error is cached, and all
+///    later calls return a clone of that error without running their
+///    initialiser.
+pub struct MonoInit<T, E> {
+    value: MonoBox<T>,
+    error: MonoBox<E>,
+    state: AtomicU8,
+    sticky: bool,
+}
+
+/// Returns a [`MonoInit`] to the empty state if the initialiser
+/// unwinds, so that waiting threads don't spin forever.
+struct ResetOnUnwind<'a>(&'a AtomicU8);
+
+impl Drop for ResetOnUnwind<'_> {
+    fn drop(&mut self) {
+        self.0.store(EMPTY, Ordering::Release);
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Addresses of the [`MonoInit`]s whose initialiser is running on
+    /// the current thread.
+    static ACTIVE_INITS: core::cell::RefCell<std::vec::Vec<usize>> =
+        const { core::cell::RefCell::new(std::vec::Vec::new()) };
+}
+
+/// Marks a [`MonoInit`] as being initialised by the current thread
+/// until dropped, so that reentrant initialisation panics instead of
+/// spinning forever.  Only tracks anything with the `std` feature.
+struct Reentrancy(#[cfg(feature = "std")] usize);
+
+impl Reentrancy {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn enter(_key: usize) -> Self {
+        #[cfg(feature = "std")]
+        ACTIVE_INITS.with(|active| active.borrow_mut().push(_key));
+        Reentrancy(
+            #[cfg(feature = "std")]
+            _key,
+        )
+    }
+
+    /// Panics if the current thread is already initialising the
+    /// [`MonoInit`] at `_key`.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn check(_key: usize) {
+        #[cfg(feature = "std")]
+        ACTIVE_INITS.with(|active| {
+            assert!(
+                !active.borrow().contains(&_key),
+                "reentrant initialization of MonoInit"
+            )
+        });
+    }
+}
+
+impl Drop for Reentrancy {
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        ACTIVE_INITS.with(|active| {
+            let mut active = active.borrow_mut();
+            if let Some(idx) = active.iter().rposition(|key| *key == self.0) {
+                active.swap_remove(idx);
+            }
+        });
+    }
+}
+
+impl<T, E> MonoInit<T, E> {
+    /// Returns a fresh, empty, [`MonoInit`] that retries after
+    /// failed initialisations.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn new() -> Self {
+        Self::with_policy(false)
+    }
+
+    /// Returns a fresh, empty, [`MonoInit`] that remembers the first
+    /// failed initialisation, and returns the same error from then
+    /// on.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn sticky() -> Self {
+        Self::with_policy(true)
+    }
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn with_policy(sticky: bool) -> Self {
+        Self {
+            value: MonoBox::empty(),
+            error: MonoBox::empty(),
+            state: AtomicU8::new(EMPTY),
+            sticky,
+        }
+    }
+
+    /// Gets the value stored in this [`MonoInit`], if any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Gets the error cached in this [`MonoInit`], if any.  Only
+    /// [`MonoInit::sticky`] containers cache errors.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Gets the value stored in this [`MonoInit`], or attempts to
+    /// initialise it with the result of `f` if the [`MonoInit`] is
+    /// empty.
+    ///
+    /// At most one thread runs its `f` at any given time; concurrent
+    /// callers spin until that `f` returns, and then either return
+    /// the new value, or, if `f` failed, follow the [`MonoInit`]'s
+    /// error policy: retry with their own `f`, or return the cached
+    /// error.  If `f` panics, the [`MonoInit`] is left empty.
+    ///
+    /// Calling `get_or_try_init_cached` on the same [`MonoInit`] from
+    /// within `f` is a bug: with the `std` feature, the reentrant
+    /// call panics with "reentrant initialization"; without `std`, it
+    /// spins forever.
+    pub fn get_or_try_init_cached(&self, f: impl FnOnce() -> Result<Box<T>, E>) -> Result<&T, E>
+    where
+        E: Clone,
+    {
+        let key = self as *const Self as usize;
+
+        loop {
+            if let Some(value) = self.value.as_ref() {
+                return Ok(value);
+            }
+
+            match self.state.compare_exchange(
+                EMPTY,
+                INITIALIZING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(ERRORED) => {
+                    return Err(self
+                        .error
+                        .as_ref()
+                        .expect("errored MonoInit must cache an error")
+                        .clone())
+                }
+                // Either some other thread is initialising, or it
+                // just finished and we'll find the value on the next
+                // iteration.  Spinning on our own initialisation
+                // would never terminate.
+                Err(_) => {
+                    Reentrancy::check(key);
+                    core::hint::spin_loop()
+                }
+            }
+        }
+
+        let reset = ResetOnUnwind(&self.state);
+        let reentrancy = Reentrancy::enter(key);
+        let result = f();
+        core::mem::drop(reentrancy);
+
+        // Only disarm `reset` right before publishing the final state:
+        // `E::clone` is user code, and may also unwind.
+        match result {
+            Ok(value) => {
+                // We own the initialisation, so the store can't fail.
+                let _ = self.value.store(value);
+                core::mem::forget(reset);
+                self.state.store(DONE, Ordering::Release);
+                Ok(self
+                    .value
+                    .as_ref()
+                    .expect("MonoInit must be populated after a store"))
+            }
+            Err(error) => {
+                if self.sticky {
+                    let _ = self.error.store(Box::new(error.clone()));
+                    core::mem::forget(reset);
+                    self.state.store(ERRORED, Ordering::Release);
+                } else {
+                    core::mem::forget(reset);
+                    self.state.store(EMPTY, Ordering::Release);
+                }
+
+                Err(error)
+            }
+        }
+    }
+
+    /// Consumes this [`MonoInit`], returning the wrapped value, if
+    /// any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_inner(self) -> Option<Box<T>> {
+        let MonoInit { value, .. } = self;
+        value.into_inner()
+    }
+}
+
+impl<T, E> Default for MonoInit<T, E> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: core::fmt::Debug, E: core::fmt::Debug> core::fmt::Debug for MonoInit<T, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MonoInit")
+            .field("value", &self.get())
+            .field("error", &self.error())
+            .finish()
+    }
+}
+
+#[test]
+fn test_success() {
+    let init: MonoInit<usize, ()> = MonoInit::new();
+
+    assert_eq!(init.get(), None);
+    assert_eq!(init.get_or_try_init_cached(|| Ok(Box::new(1))), Ok(&1));
+    assert_eq!(init.get_or_try_init_cached(|| Ok(Box::new(2))), Ok(&1));
+    assert_eq!(init.get_or_try_init_cached(|| Err(())), Ok(&1));
+    assert_eq!(init.get(), Some(&1));
+    assert_eq!(init.into_inner(), Some(Box::new(1)));
+}
+
+#[test]
+fn test_transient_error() {
+    let init: MonoInit<usize, &str> = Default::default();
+
+    assert_eq!(init.get_or_try_init_cached(|| Err("failed")), Err("failed"));
+    assert_eq!(init.get(), None);
+    assert_eq!(init.error(), None);
+
+    assert_eq!(init.get_or_try_init_cached(|| Ok(Box::new(2))), Ok(&2));
+    assert_eq!(
+        format!("{:?}", init),
+        "MonoInit { value: Some(2), error: None }"
+    );
+}
+
+#[test]
+fn test_sticky_error() {
+    let init: MonoInit<usize, &str> = MonoInit::sticky();
+
+    assert_eq!(init.get_or_try_init_cached(|| Err("failed")), Err("failed"));
+    assert_eq!(init.error(), Some(&"failed"));
+
+    assert_eq!(
+        init.get_or_try_init_cached(|| panic!("should not be called")),
+        Err("failed")
+    );
+    assert_eq!(init.get(), None);
+}
+
+#[test]
+fn test_race() {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Barrier;
+
+    let init: MonoInit<usize, ()> = MonoInit::new();
+    let calls = AtomicUsize::new(0);
+    let barrier = Barrier::new(2);
+
+    let values: Vec<usize> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..2)
+            .map(|i| {
+                let init = &init;
+                let calls = &calls;
+                let barrier = &barrier;
+                s.spawn(move || {
+                    barrier.wait();
+                    *init
+                        .get_or_try_init_cached(|| {
+                            calls.fetch_add(1, Ordering::Relaxed);
+                            std::thread::sleep(std::time::Duration::from_millis(10));
+                            Ok(Box::new(i))
+                        })
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(values[0], values[1]);
+    assert_eq!(init.get(), Some(&values[0]));
+}
+
+#[test]
+fn test_panic() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let init: MonoInit<usize, &str> = MonoInit::sticky();
+
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        let _ = init.get_or_try_init_cached(|| panic!("init failed"));
+    }))
+    .is_err());
+    // A panic is neither a value nor a cached error: the next caller
+    // gets to run its own initialiser.
+    assert_eq!(init.get(), None);
+    assert_eq!(init.error(), None);
+
+    assert_eq!(init.get_or_try_init_cached(|| Ok(Box::new(1))), Ok(&1));
+}
+
+#[test]
+fn test_panicking_clone() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    #[derive(Debug, PartialEq)]
+    struct Unclonable;
+
+    impl Clone for Unclonable {
+        fn clone(&self) -> Self {
+            panic!("clone failed")
+        }
+    }
+
+    let init: MonoInit<usize, Unclonable> = MonoInit::sticky();
+
+    assert!(catch_unwind(AssertUnwindSafe(|| {
+        let _ = init.get_or_try_init_cached(|| Err(Unclonable));
+    }))
+    .is_err());
+    // The failed clone must not leave the `MonoInit` initialising.
+    assert_eq!(init.get(), None);
+    assert_eq!(init.get_or_try_init_cached(|| Ok(Box::new(1))), Ok(&1));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_reentrant() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let init: MonoInit<usize, ()> = MonoInit::new();
+
+    let payload = catch_unwind(AssertUnwindSafe(|| {
+        let _ = init.get_or_try_init_cached(|| {
+            let inner = init.get_or_try_init_cached(|| Ok(Box::new(2)));
+            Ok(Box::new(*inner? + 1))
+        });
+    }))
+    .expect_err("reentrant initialisation must panic");
+    assert_eq!(
+        payload.downcast_ref::<&str>(),
+        Some(&"reentrant initialization of MonoInit")
+    );
+
+    // The outer initialisation unwound, so the MonoInit is still
+    // usable.
+    assert_eq!(init.get(), None);
+    assert_eq!(init.get_or_try_init_cached(|| Ok(Box::new(1))), Ok(&1));
+}
@@ -48,6 +48,121 @@ extern crate alloc;
 
 mod arc;
 mod r#box;
+mod epoch;
+#[cfg(target_has_atomic = "64")]
+mod generation;
+mod guard;
+mod init;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(target_has_atomic = "64")]
+mod niche;
+mod padded;
+mod ptr;
+mod raw;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod weak;
 
+/// Memory orderings for all atomic operations, as drop-in
+/// replacements for [`core::sync::atomic::Ordering`]'s variants
+/// (modules import this as `Ordering`).  The `seqcst` feature
+/// upgrades every ordering to `SeqCst`, to help rule out quinine when
+/// chasing memory ordering bugs.
+#[allow(non_upper_case_globals, dead_code)]
+mod ordering {
+    use core::sync::atomic::Ordering;
+
+    const fn upgrade(order: Ordering) -> Ordering {
+        if cfg!(feature = "seqcst") {
+            Ordering::SeqCst
+        } else {
+            order
+        }
+    }
+
+    pub(crate) const Relaxed: Ordering = upgrade(Ordering::Relaxed);
+    pub(crate) const Acquire: Ordering = upgrade(Ordering::Acquire);
+    pub(crate) const Release: Ordering = upgrade(Ordering::Release);
+    pub(crate) const AcqRel: Ordering = upgrade(Ordering::AcqRel);
+    pub(crate) const SeqCst: Ordering = Ordering::SeqCst;
+}
+
+/// Atomic primitives, swapped for shuttle's instrumented versions when
+/// building with `--cfg shuttle`.  [`AtomicU64`](core::sync::atomic::AtomicU64)
+/// only exists on targets with 64-bit atomics.
+mod sync {
+    #[cfg(all(not(shuttle), target_has_atomic = "64"))]
+    pub(crate) use core::sync::atomic::AtomicU64;
+    #[cfg(not(shuttle))]
+    pub(crate) use core::sync::atomic::{AtomicPtr, AtomicU8};
+    #[cfg(shuttle)]
+    pub(crate) use shuttle::sync::atomic::{AtomicPtr, AtomicU64, AtomicU8};
+    // Only `metrics::MonoBoxCounted` needs word-sized counters.
+    #[cfg(all(not(shuttle), feature = "metrics"))]
+    pub(crate) use core::sync::atomic::AtomicUsize;
+    #[cfg(all(shuttle, feature = "metrics"))]
+    pub(crate) use shuttle::sync::atomic::AtomicUsize;
+}
+
+pub use arc::all_populated;
+pub use arc::drop_all;
+pub use arc::fill_from;
+pub use arc::try_freeze_all;
+pub use arc::Filled;
 pub use arc::MonoArc;
+pub use arc::MonoSnapshot;
+pub use arc::Observed;
+pub use arc::ProjectedArc;
+pub use arc::StoreGuard;
+pub use arc::StoreReject;
+pub use epoch::EpochBox;
+#[cfg(target_has_atomic = "64")]
+pub use generation::MonoArcGen;
+pub use guard::Guard;
+pub use guard::Guarded;
+pub use init::MonoInit;
+#[cfg(target_has_atomic = "64")]
+pub use niche::MonoNiche;
+pub use padded::MonoArcPadded;
+pub use ptr::MonoPtr;
+pub use r#box::ChildRef;
+pub use r#box::ContainerDebug;
 pub use r#box::MonoBox;
+pub use r#box::ScopedStore;
+pub use raw::RawHandle;
+#[cfg(feature = "serde")]
+pub use serde_impls::serde_as_option;
+#[cfg(feature = "serde")]
+pub use serde_impls::serde_as_value;
+#[cfg(feature = "serde")]
+pub use serde_impls::SerdeContainer;
+pub use weak::WeakCache;
+
+#[cfg(feature = "seqcst")]
+#[test]
+fn test_seqcst() {
+    use core::sync::atomic::Ordering::SeqCst;
+
+    for order in [
+        ordering::Relaxed,
+        ordering::Acquire,
+        ordering::Release,
+        ordering::AcqRel,
+    ] {
+        assert_eq!(order, SeqCst);
+    }
+
+    // Stronger orderings can't change single-threaded behaviour.
+    let mut boxed: MonoBox<usize> = Default::default();
+    assert!(boxed.store_value(1));
+    assert!(!boxed.store_value(2));
+    assert_eq!(boxed.as_ref(), Some(&1));
+    assert_eq!(boxed.take(), Some(Box::new(1)));
+
+    let arc: MonoArc<usize> = Default::default();
+    assert!(arc.store_value(1));
+    assert!(!arc.close());
+    assert_eq!(arc.get().as_deref(), Some(&1));
+    assert_eq!(arc.clone().into_inner().as_deref(), Some(&1));
+}
@@ -0,0 +1,223 @@
+//! Global event counters for [`MonoArc`](crate::MonoArc)s, enabled by
+//! the `metrics` feature.
+//!
+//! The counters are shared by every [`MonoArc`](crate::MonoArc) in
+//! the process, and only ever increase: callers who want rates should
+//! diff [`snapshot`]s.  Counters are updated with `Relaxed` adds, so
+//! they're only approximately consistent with each other.  They're
+//! also only word-sized, and thus wrap around after 2^32 events on
+//! 32-bit targets.
+//!
+//! [`MonoBoxCounted`] instead counts wasted initialisations for a
+//! single [`MonoBox`].
+extern crate alloc;
+
+use alloc::boxed::Box;
+
+use crate::ordering as Ordering;
+use crate::sync::AtomicUsize;
+use crate::MonoBox;
+
+// Shuttle's atomics only work inside a shuttle execution, and these
+// counters outlive every execution: keep the globals on plain atomics,
+// even under `cfg(shuttle)`.  Counters are word-sized, for targets
+// without 64-bit atomics.
+use core::sync::atomic::AtomicUsize as GlobalCounter;
+
+static STORED: GlobalCounter = GlobalCounter::new(0);
+static CONTENDED: GlobalCounter = GlobalCounter::new(0);
+static EMPTY_READS: GlobalCounter = GlobalCounter::new(0);
+
+/// A [`MetricsSnapshot`] holds the values of the global counters at
+/// one point in time, as returned by [`snapshot`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MetricsSnapshot {
+    /// The number of stores that populated a [`MonoArc`](crate::MonoArc).
+    pub stored: u64,
+    /// The number of stores that failed because the
+    /// [`MonoArc`](crate::MonoArc) was already populated or closed.
+    pub contended: u64,
+    /// The number of reads that found an empty (or closed)
+    /// [`MonoArc`](crate::MonoArc).
+    pub empty_reads: u64,
+}
+
+/// Returns the current values of the global counters.
+pub fn snapshot() -> MetricsSnapshot {
+    MetricsSnapshot {
+        stored: STORED.load(Ordering::Relaxed) as u64,
+        contended: CONTENDED.load(Ordering::Relaxed) as u64,
+        empty_reads: EMPTY_READS.load(Ordering::Relaxed) as u64,
+    }
+}
+
+#[cfg_attr(not(tarpaulin), inline(always))]
+pub(crate) fn record_store(won: bool) {
+    let counter = if won { &STORED } else { &CONTENDED };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg_attr(not(tarpaulin), inline(always))]
+pub(crate) fn record_empty_read() {
+    EMPTY_READS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// A [`MonoBoxCounted<T>`] is a [`MonoBox<T>`] that counts the values
+/// its [`MonoBoxCounted::get_or_try_init`] and
+/// [`MonoBoxCounted::get_or_compute`] built and then dropped, after
+/// losing the race to populate the container.  High counts suggest
+/// serialising initialisation with a [`MonoInit`](crate::MonoInit)
+/// instead.
+///
+/// The counter lives outside the [`MonoBox`], which keeps its
+/// `#[repr(transparent)]` layout.  [`MonoBoxCounted`] dereferences to
+/// the underlying [`MonoBox`] for everything else.
+pub struct MonoBoxCounted<T> {
+    mono: MonoBox<T>,
+    wasted_inits: AtomicUsize,
+}
+
+impl<T> MonoBoxCounted<T> {
+    /// Returns a fresh [`MonoBoxCounted`] that holds `inner`.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn new(inner: Option<Box<T>>) -> Self {
+        MonoBox::new(inner).into()
+    }
+
+    /// Returns a fresh [`MonoBoxCounted`] that holds [`None`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn empty() -> Self {
+        Self::new(None)
+    }
+
+    /// Returns the number of values built and then dropped after
+    /// losing the race to populate this container.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn wasted_inits(&self) -> usize {
+        self.wasted_inits.load(Ordering::Relaxed)
+    }
+
+    /// Counts wasted values, and otherwise behaves like
+    /// [`MonoBox::get_or_try_init`].
+    pub fn get_or_try_init<E>(&self, f: impl FnOnce() -> Result<Box<T>, E>) -> Result<&T, E> {
+        if let Some(value) = self.mono.as_ref() {
+            return Ok(value);
+        }
+
+        // If we lost the race, drop our value and use the winner's.
+        if self.mono.store(f()?).is_err() {
+            self.wasted_inits.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(self
+            .mono
+            .as_ref()
+            .expect("MonoBox must be populated after a store"))
+    }
+
+    /// Counts wasted values, and otherwise behaves like
+    /// [`MonoBox::get_or_compute`].
+    pub fn get_or_compute(&self, compute: impl FnOnce() -> T) -> &T {
+        match self.get_or_try_init(|| Ok::<_, core::convert::Infallible>(Box::new(compute()))) {
+            Ok(value) => value,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Consumes this [`MonoBoxCounted`], returning the underlying
+    /// [`MonoBox`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_mono(self) -> MonoBox<T> {
+        self.mono
+    }
+}
+
+impl<T> core::ops::Deref for MonoBoxCounted<T> {
+    type Target = MonoBox<T>;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn deref(&self) -> &MonoBox<T> {
+        &self.mono
+    }
+}
+
+impl<T> Default for MonoBoxCounted<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for MonoBoxCounted<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MonoBoxCounted")
+            .field("value", &self.mono.as_ref())
+            .field("wasted_inits", &self.wasted_inits())
+            .finish()
+    }
+}
+
+impl<T> From<MonoBox<T>> for MonoBoxCounted<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(mono: MonoBox<T>) -> MonoBoxCounted<T> {
+        MonoBoxCounted {
+            mono,
+            wasted_inits: AtomicUsize::new(0),
+        }
+    }
+}
+
+#[test]
+fn test_counters() {
+    use crate::MonoArc;
+
+    // Other tests run concurrently, so we can only check lower bounds.
+    let before = snapshot();
+    let mono = MonoArc::<usize>::empty();
+
+    assert_eq!(mono.as_ref(), None);
+    assert_eq!(mono.get(), None);
+    assert!(mono.store_value(1));
+    assert!(!mono.store_value(2));
+    assert!(mono.store_detailed(std::sync::Arc::new(3)).is_err());
+
+    let after = snapshot();
+    assert!(after.stored > before.stored);
+    assert!(after.contended >= before.contended + 2);
+    assert!(after.empty_reads >= before.empty_reads + 2);
+}
+
+#[test]
+fn test_wasted_inits() {
+    use std::sync::Barrier;
+
+    const THREADS: usize = 4;
+
+    let mono: MonoBoxCounted<usize> = MonoBoxCounted::empty();
+    assert_eq!(mono.get_or_compute(|| 1), &1);
+    assert_eq!(mono.get_or_compute(|| 2), &1);
+    assert_eq!(mono.wasted_inits(), 0);
+    assert_eq!(
+        format!("{:?}", mono),
+        "MonoBoxCounted { value: Some(1), wasted_inits: 0 }"
+    );
+
+    // Make every thread build its value before anyone stores.
+    let contended: MonoBoxCounted<usize> = Default::default();
+    let barrier = Barrier::new(THREADS);
+    std::thread::scope(|s| {
+        for id in 0..THREADS {
+            let (contended, barrier) = (&contended, &barrier);
+            s.spawn(move || {
+                let _ = contended.get_or_try_init(|| -> Result<_, ()> {
+                    barrier.wait();
+                    Ok(Box::new(id))
+                });
+            });
+        }
+    });
+
+    assert!(contended.wasted_inits() > 0);
+    assert_eq!(contended.wasted_inits(), THREADS - 1);
+    assert!(contended.into_mono().is_some());
+}
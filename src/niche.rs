@@ -0,0 +1,200 @@
+use core::convert::TryFrom;
+use core::num::NonZeroU64;
+
+use crate::ordering as Ordering;
+use crate::sync::AtomicU64;
+
+/// A [`MonoNiche<T>`] is an atomic, lock-free, write-once
+/// [`Option<T>`] for small `Copy` types that convert to and from a
+/// [`NonZeroU64`], e.g., [`NonZeroU32`](core::num::NonZeroU32) or
+/// [`NonZeroU64`] itself.
+///
+/// The value lives directly in an [`AtomicU64`](core::sync::atomic::AtomicU64),
+/// without any allocation: the zero niche represents [`None`], so
+/// there's no need for a separate initialisation flag.  That's also
+/// why [`MonoNiche`] is only available on targets with 64-bit
+/// atomics.
+pub struct MonoNiche<T> {
+    // 0 when empty, and `T`'s `NonZeroU64` representation otherwise.
+    word: AtomicU64,
+    _value: core::marker::PhantomData<T>,
+}
+
+impl<T> MonoNiche<T>
+where
+    T: Copy + Into<NonZeroU64> + TryFrom<NonZeroU64>,
+{
+    /// Returns a fresh [`MonoNiche`] that holds `inner`.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn new(inner: Option<T>) -> Self {
+        Self {
+            word: AtomicU64::new(inner.map_or(0, |value| value.into().get())),
+            _value: Default::default(),
+        }
+    }
+
+    /// Returns a fresh [`MonoNiche`] that holds [`None`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn empty() -> Self {
+        Self::new(None)
+    }
+
+    /// Returns whether the [`MonoNiche`]'s value is [`None`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn is_none(&self) -> bool {
+        self.word.load(Ordering::Relaxed) == 0
+    }
+
+    /// Returns whether the [`MonoNiche`]'s value is [`Some`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn is_some(&self) -> bool {
+        !self.is_none()
+    }
+
+    /// Attempts to store `value` in this [`MonoNiche`].  The
+    /// operation succeeds iff it upgrades the [`MonoNiche`] from
+    /// [`None`] to [`Some`].
+    ///
+    /// Returns [`Ok`] when the store succeeds, and passes back `value`
+    /// as [`Err`] otherwise.
+    pub fn store(&self, value: T) -> Result<(), T> {
+        match self.word.compare_exchange(
+            0,
+            value.into().get(),
+            Ordering::Release,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(value),
+        }
+    }
+
+    /// Gets the value stored in this [`MonoNiche`], if any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn get(&self) -> Option<T> {
+        decode(self.word.load(Ordering::Acquire))
+    }
+
+    /// Returns the value previously stored in this [`MonoNiche`] and
+    /// replaces it with `value`.
+    pub fn swap(&mut self, value: Option<T>) -> Option<T> {
+        let new = value.map_or(0, |value| value.into().get());
+        // `&mut` excludes concurrent accesses, as in `MonoBox::swap`.
+        let old = self.word.load(Ordering::Relaxed);
+
+        self.word.store(new, Ordering::Relaxed);
+        decode(old)
+    }
+
+    /// Takes the value out of this [`MonoNiche`], leaving [`None`] in
+    /// its place.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn take(&mut self) -> Option<T> {
+        self.swap(None)
+    }
+
+    /// Consumes this [`MonoNiche`], returning the wrapped value, if
+    /// any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_inner(self) -> Option<T> {
+        self.get()
+    }
+}
+
+/// Converts a word stored in a [`MonoNiche`] back to its value.
+#[cfg_attr(not(tarpaulin), inline(always))]
+fn decode<T: TryFrom<NonZeroU64>>(word: u64) -> Option<T> {
+    // Non-zero words always come from a `T`, so the conversion back
+    // can't fail.
+    NonZeroU64::new(word).map(|word| {
+        T::try_from(word)
+            .ok()
+            .expect("MonoNiche words must round-trip")
+    })
+}
+
+impl<T> Default for MonoNiche<T>
+where
+    T: Copy + Into<NonZeroU64> + TryFrom<NonZeroU64>,
+{
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> core::fmt::Debug for MonoNiche<T>
+where
+    T: Copy + Into<NonZeroU64> + TryFrom<NonZeroU64> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.get(), f)
+    }
+}
+
+impl<T> From<T> for MonoNiche<T>
+where
+    T: Copy + Into<NonZeroU64> + TryFrom<NonZeroU64>,
+{
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(value: T) -> Self {
+        Self::new(Some(value))
+    }
+}
+
+#[test]
+fn test_store_get() {
+    let one = NonZeroU64::new(1).unwrap();
+    let two = NonZeroU64::new(2).unwrap();
+
+    let mono = MonoNiche::<NonZeroU64>::empty();
+    assert!(mono.is_none());
+    assert_eq!(mono.get(), None);
+    assert_eq!(format!("{:?}", mono), "None");
+
+    assert_eq!(mono.store(one), Ok(()));
+    assert!(mono.is_some());
+    assert_eq!(mono.get(), Some(one));
+
+    assert_eq!(mono.store(two), Err(two));
+    assert_eq!(mono.get(), Some(one));
+    assert_eq!(mono.into_inner(), Some(one));
+
+    // `u64::MAX` is a regular value; only zero means empty.
+    let max = NonZeroU64::new(u64::MAX).unwrap();
+    assert_eq!(MonoNiche::from(max).get(), Some(max));
+}
+
+#[test]
+fn test_narrow() {
+    use core::num::NonZeroU32;
+
+    let value = NonZeroU32::new(u32::MAX).unwrap();
+    let mut mono = MonoNiche::<NonZeroU32>::default();
+
+    assert_eq!(mono.store(value), Ok(()));
+    assert_eq!(mono.get(), Some(value));
+    assert_eq!(mono.take(), Some(value));
+    assert!(mono.is_none());
+    assert_eq!(mono.swap(Some(value)), None);
+    assert_eq!(mono.get(), Some(value));
+}
+
+#[test]
+fn test_race() {
+    let mono = MonoNiche::<NonZeroU64>::empty();
+
+    let wins: usize = std::thread::scope(|s| {
+        let workers: Vec<_> = (1..=4)
+            .map(|i| {
+                let mono = &mono;
+                s.spawn(move || mono.store(NonZeroU64::new(i).unwrap()).is_ok() as usize)
+            })
+            .collect();
+
+        workers.into_iter().map(|w| w.join().unwrap()).sum()
+    });
+
+    assert_eq!(wins, 1);
+    assert!(mono.is_some());
+}
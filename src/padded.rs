@@ -0,0 +1,128 @@
+extern crate alloc;
+
+use alloc::sync::Arc;
+
+use crate::MonoArc;
+
+/// A [`MonoArcPadded<T>`] is a [`MonoArc<T>`] aligned (and thus padded)
+/// to a 64-byte cache line.
+///
+/// Arrays of plain [`MonoArc`]s pack 8 containers per cache line, so
+/// stores to one container contend with accesses to its neighbours.
+/// [`MonoArcPadded`] trades memory for independence: each container
+/// gets its own cache line.
+///
+/// [`MonoArcPadded`] dereferences to the underlying [`MonoArc`], so
+/// it exposes the full [`MonoArc`] API.
+#[repr(align(64))]
+pub struct MonoArcPadded<T> {
+    mono: MonoArc<T>,
+}
+
+impl<T> MonoArcPadded<T> {
+    /// Returns a fresh [`MonoArcPadded`] that holds `inner`.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn new(inner: Option<Arc<T>>) -> Self {
+        MonoArc::new(inner).into()
+    }
+
+    /// Returns a fresh [`MonoArcPadded`] that holds [`None`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn empty() -> Self {
+        Self::new(None)
+    }
+
+    /// Consumes this [`MonoArcPadded`], returning the underlying
+    /// [`MonoArc`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_mono(self) -> MonoArc<T> {
+        self.mono
+    }
+}
+
+impl<T> core::ops::Deref for MonoArcPadded<T> {
+    type Target = MonoArc<T>;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn deref(&self) -> &MonoArc<T> {
+        &self.mono
+    }
+}
+
+impl<T> core::ops::DerefMut for MonoArcPadded<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn deref_mut(&mut self) -> &mut MonoArc<T> {
+        &mut self.mono
+    }
+}
+
+impl<T> Default for MonoArcPadded<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> Clone for MonoArcPadded<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn clone(&self) -> Self {
+        self.mono.clone().into()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for MonoArcPadded<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.mono, f)
+    }
+}
+
+impl<T> From<MonoArc<T>> for MonoArcPadded<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(mono: MonoArc<T>) -> MonoArcPadded<T> {
+        MonoArcPadded { mono }
+    }
+}
+
+impl<T> From<MonoArcPadded<T>> for MonoArc<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from(padded: MonoArcPadded<T>) -> MonoArc<T> {
+        padded.into_mono()
+    }
+}
+
+#[test]
+fn test_layout() {
+    assert_eq!(core::mem::align_of::<MonoArcPadded<u8>>(), 64);
+    assert_eq!(core::mem::size_of::<MonoArcPadded<u8>>(), 64);
+
+    let table: Vec<MonoArcPadded<u8>> = (0..2).map(|_| Default::default()).collect();
+    let delta = (&table[1] as *const _ as usize) - (&table[0] as *const _ as usize);
+    assert_eq!(delta, 64);
+}
+
+#[test]
+fn test_forwarding() {
+    let mut mono: MonoArcPadded<Vec<usize>> = MonoArcPadded::empty();
+
+    assert!(mono.is_none());
+    assert!(mono.store_value(vec![1]));
+    assert_eq!(mono.as_ref().unwrap(), &[1]);
+    assert_eq!(mono.clone().get(), Some(Arc::new(vec![1])));
+    assert_eq!(format!("{:?}", mono), "Some([1])");
+
+    assert_eq!(mono.take(), Some(Arc::new(vec![1])));
+    assert!(mono.is_none());
+
+    let mono: MonoArcPadded<usize> = MonoArcPadded::new(Some(Arc::new(2)));
+    let inner: MonoArc<usize> = mono.into();
+    assert_eq!(inner.as_ref(), Some(&2));
+}
+
+#[test]
+fn test_default() {
+    struct NoDefault;
+
+    let mono: MonoArcPadded<NoDefault> = Default::default();
+    assert!(mono.is_none());
+    assert!(mono.store_value(NoDefault));
+}
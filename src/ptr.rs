@@ -0,0 +1,146 @@
+use core::ptr::NonNull;
+
+use crate::ordering as Ordering;
+use crate::sync::AtomicPtr;
+
+/// A [`MonoPtr<T>`] is a write-once pointer to a `T`, like a
+/// [`MonoBox<T>`](crate::MonoBox), but for values that weren't
+/// allocated with [`Box`](alloc::boxed::Box): when dropped, a
+/// populated [`MonoPtr`] passes its pointer to the `free` function
+/// supplied at construction time, instead of
+/// [`Box::from_raw`](alloc::boxed::Box::from_raw).
+///
+/// This gives callers full control over reclamation, e.g., for
+/// values carved out of an arena or a foreign allocator.
+pub struct MonoPtr<T> {
+    ptr_or_null: AtomicPtr<T>,
+    free: fn(*mut T),
+    // We own the pointee.
+    _owns: core::marker::PhantomData<T>,
+}
+
+impl<T> MonoPtr<T> {
+    /// Returns a fresh empty [`MonoPtr`] that will release its value,
+    /// if any, by calling `free`.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn empty(free: fn(*mut T)) -> Self {
+        Self {
+            ptr_or_null: AtomicPtr::new(core::ptr::null_mut()),
+            free,
+            _owns: Default::default(),
+        }
+    }
+
+    /// Returns whether the [`MonoPtr`]'s value is [`None`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn is_none(&self) -> bool {
+        self.ptr_or_null.load(Ordering::Relaxed).is_null()
+    }
+
+    /// Returns whether the [`MonoPtr`]'s value is [`Some`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn is_some(&self) -> bool {
+        !self.is_none()
+    }
+
+    /// Attempts to store `ptr` in this [`MonoPtr`].  The operation
+    /// succeeds iff it upgrades the [`MonoPtr`] from [`None`] to
+    /// [`Some`].
+    ///
+    /// Returns [`Ok`] when the store succeeds, and passes back `ptr`
+    /// as [`Err`] otherwise; the caller is then still responsible for
+    /// `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid `T`, and its ownership transfers to
+    /// the [`MonoPtr`] on success: nothing else may mutate or release
+    /// the pointee, and it must be sound to pass `ptr` to this
+    /// [`MonoPtr`]'s `free` function once the [`MonoPtr`] is dropped.
+    pub unsafe fn store(&self, ptr: NonNull<T>) -> Result<(), NonNull<T>> {
+        match self.ptr_or_null.compare_exchange(
+            core::ptr::null_mut(),
+            ptr.as_ptr(),
+            Ordering::Release,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(ptr),
+        }
+    }
+
+    /// Gets the value stored in this [`MonoPtr`], if any.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn as_ref(&self) -> Option<&T> {
+        let ptr = self.ptr_or_null.load(Ordering::Acquire);
+        unsafe { ptr.as_ref() }
+    }
+
+    /// Consumes this [`MonoPtr`], returning the stored pointer, if
+    /// any, without calling `free`: the caller becomes responsible
+    /// for releasing the pointee.
+    pub fn into_raw(self) -> Option<NonNull<T>> {
+        let this = core::mem::ManuallyDrop::new(self);
+        NonNull::new(this.ptr_or_null.load(Ordering::Relaxed))
+    }
+}
+
+impl<T> Drop for MonoPtr<T> {
+    fn drop(&mut self) {
+        let ptr = self.ptr_or_null.load(Ordering::Relaxed);
+
+        if !ptr.is_null() {
+            (self.free)(ptr);
+        }
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for MonoPtr<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.as_ref(), f)
+    }
+}
+
+#[test]
+fn test_custom_free() {
+    use std::sync::atomic::AtomicUsize;
+
+    static FREED: AtomicUsize = AtomicUsize::new(0);
+
+    // Pretend values come from some other allocator, and count
+    // releases.
+    fn alloc(value: usize) -> NonNull<usize> {
+        NonNull::from(Box::leak(Box::new(value)))
+    }
+
+    fn free(ptr: *mut usize) {
+        FREED.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+        core::mem::drop(unsafe { Box::from_raw(ptr) });
+    }
+
+    let mono = MonoPtr::empty(free);
+    assert!(mono.is_none());
+    assert_eq!(format!("{:?}", mono), "None");
+
+    assert_eq!(unsafe { mono.store(alloc(1)) }, Ok(()));
+    assert!(mono.is_some());
+    assert_eq!(mono.as_ref(), Some(&1));
+
+    // Failed stores hand the pointer back, and don't free anything.
+    let rejected = unsafe { mono.store(alloc(2)) }.expect_err("populated");
+    assert_eq!(FREED.load(core::sync::atomic::Ordering::Relaxed), 0);
+    free(rejected.as_ptr());
+    assert_eq!(FREED.load(core::sync::atomic::Ordering::Relaxed), 1);
+
+    std::mem::drop(mono);
+    assert_eq!(FREED.load(core::sync::atomic::Ordering::Relaxed), 2);
+
+    // Empty `MonoPtr`s don't call `free`, nor do `into_raw`ed ones.
+    std::mem::drop(MonoPtr::empty(free));
+    let mono = MonoPtr::empty(free);
+    assert_eq!(unsafe { mono.store(alloc(3)) }, Ok(()));
+    let raw = mono.into_raw().expect("populated");
+    assert_eq!(FREED.load(core::sync::atomic::Ordering::Relaxed), 2);
+    free(raw.as_ptr());
+    assert_eq!(FREED.load(core::sync::atomic::Ordering::Relaxed), 3);
+}
@@ -0,0 +1,138 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::ptr::NonNull;
+
+use crate::MonoArc;
+use crate::MonoBox;
+
+/// A [`RawHandle<T>`] owns the raw pointer extracted from a populated
+/// [`MonoBox`] or [`MonoArc`], and releases it when dropped, unless
+/// [`RawHandle::into_raw`] takes back ownership first.
+///
+/// This is a leak-free intermediate state for FFI code: early returns
+/// drop the handle, and thus free the value (or release the [`Arc`]'s
+/// strong reference), while the success path hands the raw pointer
+/// off with [`RawHandle::into_raw`].
+pub struct RawHandle<T> {
+    ptr: NonNull<T>,
+    release: unsafe fn(*mut T),
+}
+
+/// Frees a pointer obtained from [`Box::into_raw`].
+unsafe fn release_box<T>(ptr: *mut T) {
+    core::mem::drop(Box::from_raw(ptr));
+}
+
+/// Releases a strong reference obtained from [`Arc::into_raw`].
+unsafe fn release_arc<T>(ptr: *mut T) {
+    core::mem::drop(Arc::from_raw(ptr as *const T));
+}
+
+impl<T> RawHandle<T> {
+    /// Returns the owned pointer, without releasing ownership.
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn as_ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Consumes this [`RawHandle`] and returns the raw pointer, whose
+    /// ownership transfers to the caller.  The pointer comes from
+    /// [`Box::into_raw`] for handles created with
+    /// [`MonoBox::into_raw_handle`], and from [`Arc::into_raw`] for
+    /// handles created with [`MonoArc::into_raw_handle`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn into_raw(self) -> *mut T {
+        let this = core::mem::ManuallyDrop::new(self);
+        this.ptr.as_ptr()
+    }
+}
+
+impl<T> Drop for RawHandle<T> {
+    fn drop(&mut self) {
+        unsafe { (self.release)(self.ptr.as_ptr()) };
+    }
+}
+
+impl<T> MonoBox<T> {
+    /// Consumes this [`MonoBox`], and returns a [`RawHandle`] that
+    /// owns its value's [`Box::into_raw`] pointer, or [`None`] if the
+    /// [`MonoBox`] is empty.
+    pub fn into_raw_handle(self) -> Option<RawHandle<T>> {
+        self.into_inner().map(|value| RawHandle {
+            ptr: NonNull::from(Box::leak(value)),
+            release: release_box::<T>,
+        })
+    }
+}
+
+impl<T> MonoArc<T> {
+    /// Consumes this [`MonoArc`], and returns a [`RawHandle`] that
+    /// owns its value's [`Arc::into_raw`] pointer (and thus one
+    /// strong reference), or [`None`] if the [`MonoArc`] is empty.
+    pub fn into_raw_handle(self) -> Option<RawHandle<T>> {
+        self.into_arc().map(|arc| RawHandle {
+            ptr: unsafe { NonNull::new_unchecked(Arc::into_raw(arc) as *mut T) },
+            release: release_arc::<T>,
+        })
+    }
+}
+
+#[test]
+fn test_drop_reclaims() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct DropTracker<'a> {
+        counter: &'a AtomicUsize,
+    }
+
+    impl Drop for DropTracker<'_> {
+        fn drop(&mut self) {
+            self.counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let counter = AtomicUsize::new(0);
+
+    assert!(MonoBox::<usize>::empty().into_raw_handle().is_none());
+    assert!(MonoArc::<usize>::empty().into_raw_handle().is_none());
+
+    let handle = MonoBox::from(DropTracker { counter: &counter })
+        .into_raw_handle()
+        .expect("populated");
+    assert!(!handle.as_ptr().is_null());
+    assert_eq!(counter.load(Ordering::Relaxed), 0);
+    std::mem::drop(handle);
+    assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+    let arc = Arc::new(DropTracker { counter: &counter });
+    let handle = MonoArc::<DropTracker>::from(arc.clone())
+        .into_raw_handle()
+        .expect("populated");
+    assert_eq!(Arc::strong_count(&arc), 2);
+    std::mem::drop(handle);
+    assert_eq!(Arc::strong_count(&arc), 1);
+    std::mem::drop(arc);
+    assert_eq!(counter.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn test_into_raw() {
+    let handle = MonoBox::from(1usize).into_raw_handle().expect("populated");
+    let ptr = handle.as_ptr();
+    assert_eq!(handle.into_raw(), ptr);
+    // We own the pointer now.
+    assert_eq!(unsafe { Box::from_raw(ptr) }, Box::new(1));
+
+    let arc = Arc::new(2usize);
+    let ptr = MonoArc::<usize>::from(arc.clone())
+        .into_raw_handle()
+        .expect("populated")
+        .into_raw();
+    assert_eq!(Arc::strong_count(&arc), 2);
+    let owned = unsafe { Arc::from_raw(ptr as *const usize) };
+    assert!(Arc::ptr_eq(&owned, &arc));
+    std::mem::drop(owned);
+    assert_eq!(Arc::strong_count(&arc), 1);
+}
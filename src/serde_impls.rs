@@ -0,0 +1,176 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+
+use crate::MonoArc;
+use crate::MonoBox;
+
+/// A [`SerdeContainer`] is a write-once container that
+/// [`serde_as_value`] and [`serde_as_option`] know how to serialise
+/// and deserialise: [`MonoBox<T>`] and [`MonoArc<T>`].
+pub trait SerdeContainer: Sized {
+    /// The type of the contained value.
+    type Value;
+
+    /// Gets the value stored in the container, if any.
+    fn value(&self) -> Option<&Self::Value>;
+
+    /// Returns a fresh container that holds `value`.
+    fn from_value(value: Option<Self::Value>) -> Self;
+}
+
+impl<T> SerdeContainer for MonoBox<T> {
+    type Value = T;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn value(&self) -> Option<&T> {
+        self.as_ref()
+    }
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from_value(value: Option<T>) -> Self {
+        MonoBox::new(value.map(Box::new))
+    }
+}
+
+impl<T> SerdeContainer for MonoArc<T> {
+    type Value = T;
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn value(&self) -> Option<&T> {
+        self.as_ref()
+    }
+
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn from_value(value: Option<T>) -> Self {
+        MonoArc::new(value.map(Arc::new))
+    }
+}
+
+/// Serialises a populated [`MonoBox`] or [`MonoArc`] as its value
+/// itself, for use with `#[serde(with = "quinine::serde_as_value")]`.
+///
+/// Serialising an empty container fails, and deserialisation always
+/// produces a populated container.
+pub mod serde_as_value {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::SerdeContainer;
+
+    /// Serialises `container`'s value, or fails if `container` is
+    /// empty.
+    pub fn serialize<C, S>(container: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: SerdeContainer,
+        C::Value: Serialize,
+        S: Serializer,
+    {
+        match container.value() {
+            Some(value) => value.serialize(serializer),
+            None => Err(serde::ser::Error::custom(
+                "can't serialize an empty container as a value",
+            )),
+        }
+    }
+
+    /// Deserialises a value, and returns a container that holds it.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: SerdeContainer,
+        C::Value: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        C::Value::deserialize(deserializer).map(|value| C::from_value(Some(value)))
+    }
+}
+
+/// Serialises a [`MonoBox`] or [`MonoArc`] like an [`Option`] of its
+/// value, for use with `#[serde(with = "quinine::serde_as_option")]`.
+pub mod serde_as_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::SerdeContainer;
+
+    /// Serialises `container` as an [`Option`] of its value.
+    pub fn serialize<C, S>(container: &C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: SerdeContainer,
+        C::Value: Serialize,
+        S: Serializer,
+    {
+        container.value().serialize(serializer)
+    }
+
+    /// Deserialises an [`Option`] of a value, and returns a container
+    /// that holds it.
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: SerdeContainer,
+        C::Value: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<C::Value>::deserialize(deserializer).map(C::from_value)
+    }
+}
+
+#[cfg(test)]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Config {
+    #[serde(with = "serde_as_value")]
+    name: MonoBox<String>,
+    #[serde(with = "serde_as_value")]
+    id: MonoArc<u32>,
+    #[serde(with = "serde_as_option")]
+    alias: MonoBox<String>,
+    #[serde(with = "serde_as_option")]
+    parent: MonoArc<u32>,
+}
+
+#[test]
+fn test_as_value() {
+    let config = Config {
+        name: "foo".to_string().into(),
+        id: 1.into(),
+        alias: MonoBox::empty(),
+        parent: 2.into(),
+    };
+
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"name":"foo","id":1,"alias":null,"parent":2}"#);
+
+    let config: Config = serde_json::from_str(&json).unwrap();
+    assert_eq!(config.name.as_deref(), Some("foo"));
+    assert_eq!(config.id.as_ref(), Some(&1));
+    assert!(config.alias.is_none());
+    assert_eq!(config.parent.as_ref(), Some(&2));
+}
+
+#[test]
+fn test_as_option() {
+    let json = r#"{"name":"foo","id":1,"alias":"bar","parent":null}"#;
+    let config: Config = serde_json::from_str(json).unwrap();
+
+    assert_eq!(config.alias.as_deref(), Some("bar"));
+    assert!(config.parent.is_none());
+    assert_eq!(serde_json::to_string(&config).unwrap(), json);
+}
+
+#[test]
+fn test_empty_value_error() {
+    let config = Config {
+        name: MonoBox::empty(),
+        id: 1.into(),
+        alias: MonoBox::empty(),
+        parent: MonoArc::empty(),
+    };
+
+    let error = serde_json::to_string(&config).unwrap_err();
+    assert!(error.to_string().contains("empty container"));
+
+    // Missing values don't deserialise either.
+    assert!(
+        serde_json::from_str::<Config>(r#"{"name":null,"id":1,"alias":null,"parent":null}"#)
+            .is_err()
+    );
+}
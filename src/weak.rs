@@ -0,0 +1,291 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::sync::Weak;
+
+use crate::ordering as Ordering;
+use crate::sync::AtomicPtr;
+use crate::MonoBox;
+
+/// A [`WeakCache<T>`] is a self-populating cache slot that only holds
+/// a [`Weak<T>`]: [`WeakCache::get_or_init`] returns the cached value
+/// while someone else keeps it alive, and builds (and caches) a fresh
+/// one once the last strong reference is gone.
+///
+/// Unlike [`MonoArc`](crate::MonoArc), a [`WeakCache`] must be
+/// refreshed after its value dies, so it can't be write-once.  It
+/// stays monotonic by never replacing a [`Weak`] in place: each
+/// refresh appends a new [`Weak`] to an internal chain, and stale
+/// [`Weak`]s are only released by [`WeakCache::compact`] (which takes
+/// `&mut self`) or on drop.  That's how concurrent readers can
+/// upgrade the cached [`Weak`] without any reclamation scheme.
+///
+/// # Memory growth
+///
+/// A [`WeakCache`] only ever grows while shared: every refresh
+/// allocates a chain node, and the stale [`Weak`] it supersedes keeps
+/// the dead value's allocation (the [`Arc`]'s control block and the
+/// `T` itself, already dropped in place but not freed) alive until
+/// the next [`WeakCache::compact`] or until the cache is dropped.  A
+/// cache whose value dies and gets rebuilt `n` times holds on to `n`
+/// such allocations, so long-lived caches with frequent refreshes
+/// must be compacted periodically, from a context that has `&mut`
+/// access to the cache.
+///
+/// A [`WeakCache`] hands out [`Arc`]s to its value, so it's only
+/// [`Send`] or [`Sync`] when [`Arc<T>`] is:
+///
+/// ```compile_fail
+/// fn assert_sync<T: Sync>() {}
+///
+/// assert_sync::<quinine::WeakCache<core::cell::Cell<u32>>>();
+/// ```
+pub struct WeakCache<T> {
+    head: MonoBox<Node<T>>,
+    // The last node we appended to `head`'s chain, if any.  Nodes are
+    // only freed under `&mut self`, so this is never dangling while
+    // we have a `&self`.
+    latest: AtomicPtr<Node<T>>,
+    // The raw chain is auto-`Send + Sync`; inherit `Arc<T>`'s bounds
+    // instead, since we share values between callers.
+    _values: core::marker::PhantomData<Arc<T>>,
+}
+
+struct Node<T> {
+    weak: Weak<T>,
+    next: MonoBox<Node<T>>,
+}
+
+impl<T> WeakCache<T> {
+    /// Returns a fresh, empty, [`WeakCache`].
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn new() -> Self {
+        Self {
+            head: MonoBox::empty(),
+            latest: AtomicPtr::new(core::ptr::null_mut()),
+            _values: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the last node in the chain, as of some point during
+    /// the call, if any.
+    fn tail(&self) -> Option<&Node<T>> {
+        let hint = self.latest.load(Ordering::Acquire);
+        // Safety: `latest` only ever points to nodes in our chain,
+        // and those live at least as long as `&self`.
+        let mut node = unsafe { hint.as_ref() }.or_else(|| self.head.as_ref())?;
+
+        while let Some(next) = node.next.as_ref() {
+            node = next;
+        }
+
+        Some(node)
+    }
+
+    /// Gets a strong reference to the cached value, if it's still
+    /// alive.
+    pub fn get(&self) -> Option<Arc<T>> {
+        self.tail()?.weak.upgrade()
+    }
+
+    /// Gets a strong reference to the cached value if it's still
+    /// alive, and otherwise caches and returns the result of `make`.
+    ///
+    /// Racing callers may each call their own `make`; only one result
+    /// is cached, and all callers return that result if it's still
+    /// alive.
+    ///
+    /// Each refresh grows the cache by one node and one dead
+    /// allocation, until the next [`WeakCache::compact`]: see the
+    /// [type-level documentation](WeakCache#memory-growth).
+    pub fn get_or_init(&self, make: impl FnOnce() -> Arc<T>) -> Arc<T> {
+        let mut make = Some(make);
+        let mut fresh: Option<Arc<T>> = None;
+        let mut slot = match self.tail() {
+            Some(node) => match node.weak.upgrade() {
+                Some(value) => return value,
+                None => &node.next,
+            },
+            None => &self.head,
+        };
+
+        loop {
+            if let Some(node) = slot.as_ref() {
+                // Someone else appended first.  Use their value if
+                // it's the latest and still alive.
+                if node.next.is_none() {
+                    if let Some(value) = node.weak.upgrade() {
+                        return value;
+                    }
+                }
+
+                slot = &node.next;
+                continue;
+            }
+
+            let value = match fresh.take() {
+                Some(value) => value,
+                None => (make.take().expect("make is only called once"))(),
+            };
+            let node = Box::new(Node {
+                weak: Arc::downgrade(&value),
+                next: MonoBox::empty(),
+            });
+
+            if slot.store(node).is_ok() {
+                let node = slot
+                    .as_ref()
+                    .expect("MonoBox must be populated after a store");
+                self.latest
+                    .store(node as *const Node<T> as *mut Node<T>, Ordering::Release);
+                return value;
+            }
+
+            fresh = Some(value);
+        }
+    }
+
+    /// Releases all stale [`Weak`] references, and only keeps the
+    /// latest one, if any.
+    pub fn compact(&mut self) {
+        *self.latest.get_mut() = core::ptr::null_mut();
+
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+            if next.is_none() {
+                self.head.store_discarding(node);
+                break;
+            }
+        }
+    }
+
+    /// Returns the number of [`Weak`] references in this cache,
+    /// including stale ones.
+    pub fn len(&self) -> usize {
+        let mut count = 0;
+        let mut slot = &self.head;
+
+        while let Some(node) = slot.as_ref() {
+            count += 1;
+            slot = &node.next;
+        }
+
+        count
+    }
+
+    /// Returns whether this cache has never been populated (since the
+    /// last [`WeakCache::compact`]).
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+}
+
+impl<T> Drop for WeakCache<T> {
+    fn drop(&mut self) {
+        // Unlink nodes one at a time, to avoid recursing once per
+        // node in `MonoBox`'s destructor.
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+impl<T> Default for WeakCache<T> {
+    #[cfg_attr(not(tarpaulin), inline(always))]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for WeakCache<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WeakCache")
+            .field("value", &self.get())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+#[test]
+fn test_rebuild() {
+    let cache: WeakCache<usize> = Default::default();
+    assert!(cache.is_empty());
+    assert_eq!(cache.get(), None);
+
+    let first = cache.get_or_init(|| Arc::new(1));
+    assert_eq!(*first, 1);
+    assert_eq!(
+        cache.get_or_init(|| panic!("should not be called")),
+        Arc::new(1)
+    );
+    assert!(Arc::ptr_eq(&cache.get().unwrap(), &first));
+
+    // Once the last strong reference is gone, the cache rebuilds.
+    std::mem::drop(first);
+    assert_eq!(cache.get(), None);
+    let second = cache.get_or_init(|| Arc::new(2));
+    assert_eq!(*second, 2);
+    assert_eq!(cache.get(), Some(Arc::new(2)));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(
+        format!("{:?}", cache),
+        "WeakCache { value: Some(2), len: 2 }"
+    );
+}
+
+#[test]
+fn test_compact() {
+    let mut cache: WeakCache<usize> = WeakCache::new();
+
+    for i in 0..10 {
+        assert_eq!(*cache.get_or_init(|| Arc::new(i)), i);
+    }
+    assert_eq!(cache.len(), 10);
+
+    let value = cache.get_or_init(|| Arc::new(10));
+    cache.compact();
+    assert_eq!(cache.len(), 1);
+    assert!(Arc::ptr_eq(&cache.get().unwrap(), &value));
+
+    // The hint was reset, but appends still go to the end.
+    std::mem::drop(value);
+    assert_eq!(*cache.get_or_init(|| Arc::new(11)), 11);
+    assert_eq!(cache.len(), 2);
+
+    cache.compact();
+    cache.compact();
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn test_race() {
+    use std::sync::Barrier;
+
+    let cache: WeakCache<usize> = WeakCache::new();
+    let barrier = Barrier::new(4);
+
+    let values: Vec<Arc<usize>> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..4)
+            .map(|i| {
+                let cache = &cache;
+                let barrier = &barrier;
+                s.spawn(move || {
+                    barrier.wait();
+                    cache.get_or_init(|| Arc::new(i))
+                })
+            })
+            .collect();
+
+        workers.into_iter().map(|w| w.join().unwrap()).collect()
+    });
+
+    // Every caller kept its result alive, so they all agree.
+    for value in values.iter() {
+        assert!(Arc::ptr_eq(value, &values[0]));
+    }
+    assert_eq!(cache.len(), 1);
+}
@@ -0,0 +1,197 @@
+//! Randomised concurrency tests, run with
+//!
+//! ```sh
+//! RUSTFLAGS="--cfg shuttle" cargo test --release --test shuttle
+//! ```
+//!
+//! Failing schedules print a seed that `shuttle::replay` can use to
+//! reproduce them deterministically.
+#![cfg(shuttle)]
+
+use shuttle::sync::atomic::AtomicBool;
+use shuttle::sync::atomic::Ordering;
+use shuttle::sync::Arc;
+use shuttle::thread;
+
+use quinine::MonoArc;
+use quinine::MonoBox;
+
+const ITERATIONS: usize = 10_000;
+const NUM_READERS: usize = 3;
+
+/// A value that can tell whether it's been torn or dropped.
+struct Payload {
+    value: usize,
+    check: usize,
+    live: AtomicBool,
+}
+
+impl Payload {
+    fn new(value: usize) -> Payload {
+        Payload {
+            value,
+            check: !value,
+            live: AtomicBool::new(true),
+        }
+    }
+
+    fn assert_valid(&self) -> usize {
+        assert!(self.live.load(Ordering::Relaxed), "freed value");
+        assert_eq!(self.check, !self.value, "torn value");
+        self.value
+    }
+}
+
+impl Drop for Payload {
+    fn drop(&mut self) {
+        assert!(self.live.swap(false, Ordering::Relaxed), "double drop");
+    }
+}
+
+fn mono_arc_store_get_clone_drop() {
+    let mono: Arc<MonoArc<Payload>> = Default::default();
+
+    let writers: Vec<_> = (1..=2)
+        .map(|i| {
+            let mono = mono.clone();
+            thread::spawn(move || {
+                let won = mono.store(std::sync::Arc::new(Payload::new(i))).is_ok();
+                let value = MonoArc::as_ref(&mono).unwrap().assert_valid();
+                (won, value)
+            })
+        })
+        .collect();
+
+    let readers: Vec<_> = (0..NUM_READERS)
+        .map(|i| {
+            let mono = mono.clone();
+            thread::spawn(move || {
+                let seen = match i % 3 {
+                    0 => MonoArc::as_ref(&mono).map(Payload::assert_valid),
+                    1 => mono.get().map(|arc| arc.assert_valid()),
+                    _ => {
+                        let clone: MonoArc<Payload> = (*mono).clone();
+                        let seen = MonoArc::as_ref(&clone).map(Payload::assert_valid);
+                        drop(clone);
+                        seen
+                    }
+                };
+
+                // Once we've seen a value, it's frozen.
+                if let Some(seen) = seen {
+                    assert_eq!(MonoArc::as_ref(&mono).unwrap().assert_valid(), seen);
+                }
+
+                seen
+            })
+        })
+        .collect();
+
+    let results: Vec<(bool, usize)> = writers.into_iter().map(|w| w.join().unwrap()).collect();
+    assert_eq!(results.iter().filter(|(won, _)| *won).count(), 1);
+    assert_eq!(results[0].1, results[1].1);
+
+    let winner = results[0].1;
+    for reader in readers {
+        if let Some(seen) = reader.join().unwrap() {
+            assert_eq!(seen, winner);
+        }
+    }
+
+    assert_eq!(MonoArc::as_ref(&mono).unwrap().assert_valid(), winner);
+}
+
+fn mono_box_store_read() {
+    let mono: Arc<MonoBox<Payload>> = Default::default();
+
+    let writers: Vec<_> = (1..=2)
+        .map(|i| {
+            let mono = mono.clone();
+            thread::spawn(move || mono.store(Box::new(Payload::new(i))).is_ok())
+        })
+        .collect();
+
+    let readers: Vec<_> = (0..NUM_READERS)
+        .map(|_| {
+            let mono = mono.clone();
+            thread::spawn(move || MonoBox::as_ref(&mono).map(Payload::assert_valid))
+        })
+        .collect();
+
+    let wins = writers
+        .into_iter()
+        .map(|w| w.join().unwrap() as usize)
+        .sum::<usize>();
+    assert_eq!(wins, 1);
+
+    let winner = MonoBox::as_ref(&mono).unwrap().assert_valid();
+    for reader in readers {
+        if let Some(seen) = reader.join().unwrap() {
+            assert_eq!(seen, winner);
+        }
+    }
+}
+
+/// `swap`, `take` and `into_inner` use `Relaxed` loads and stores,
+/// and rely on whatever handed out exclusive ownership to order them
+/// with other threads' accesses: here, a join followed by
+/// `Arc::try_unwrap`, and a channel.
+fn exclusive_handoff() {
+    let shared: Arc<MonoBox<Payload>> = Default::default();
+    let (tx, rx) = shuttle::sync::mpsc::channel();
+
+    let writer = {
+        let shared = shared.clone();
+        thread::spawn(move || {
+            let _ = shared.store(Box::new(Payload::new(1)));
+
+            let owned = MonoArc::empty();
+            let _ = owned.store(std::sync::Arc::new(Payload::new(2)));
+            tx.send(owned).unwrap();
+        })
+    };
+
+    let reader = {
+        let shared = shared.clone();
+        thread::spawn(move || MonoBox::as_ref(&shared).map(Payload::assert_valid))
+    };
+
+    let consumer = thread::spawn(move || {
+        let mut owned: MonoArc<Payload> = rx.recv().unwrap();
+        let old = owned.swap(Some(std::sync::Arc::new(Payload::new(3))));
+        assert_eq!(old.unwrap().assert_valid(), 2);
+        assert_eq!(owned.into_inner().unwrap().assert_valid(), 3);
+    });
+
+    writer.join().unwrap();
+    if let Some(seen) = reader.join().unwrap() {
+        assert_eq!(seen, 1);
+    }
+    consumer.join().unwrap();
+
+    let mut shared = Arc::try_unwrap(shared).ok().expect("all clones joined");
+    let old = shared.swap(Some(Box::new(Payload::new(4))));
+    assert_eq!(old.unwrap().assert_valid(), 1);
+    assert_eq!(shared.take().unwrap().assert_valid(), 4);
+    assert!(shared.into_inner().is_none());
+}
+
+#[test]
+fn shuttle_mono_arc() {
+    shuttle::check_random(mono_arc_store_get_clone_drop, ITERATIONS);
+}
+
+#[test]
+fn shuttle_mono_arc_pct() {
+    shuttle::check_pct(mono_arc_store_get_clone_drop, ITERATIONS, 3);
+}
+
+#[test]
+fn shuttle_mono_box() {
+    shuttle::check_random(mono_box_store_read, ITERATIONS);
+}
+
+#[test]
+fn shuttle_exclusive_handoff() {
+    shuttle::check_random(exclusive_handoff, ITERATIONS);
+}